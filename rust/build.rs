@@ -0,0 +1,5 @@
+fn main() {
+    // exposes `Py_LIMITED_API` / `Py_3_11` cfgs, buffer protocol support
+    // depends on them
+    pyo3_build_config::use_pyo3_cfgs();
+}
@@ -0,0 +1,37 @@
+use std::time::Instant;
+
+use antelope::serializer::Decoder;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::proxies::abi_store::PyAbiStore;
+use crate::serializer::decode::decode_abi_type;
+
+/// Decode `data` as `type_name` of `account`'s ABI `iterations` times, returns
+/// the average microseconds per decode, measured in Rust to leave out python
+/// loop overhead.
+///
+/// There is no global ABI registry, so the ABI is looked up in `store`,
+/// raises `KeyError` if `account` has none loaded.
+#[pyfunction]
+pub fn benchmark_decode(
+    py: Python<'_>,
+    store: &PyAbiStore,
+    account: &str,
+    type_name: &str,
+    data: &[u8],
+    iterations: u32,
+) -> PyResult<f64> {
+    if iterations == 0 {
+        return Err(PyValueError::new_err("iterations must be greater than 0"));
+    }
+    let abi = store.abi(py, account)?;
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let mut decoder = Decoder::new(data);
+        decode_abi_type(py, &abi.get().inner, type_name, &mut decoder)?;
+    }
+
+    Ok(start.elapsed().as_secs_f64() * 1_000_000.0 / iterations as f64)
+}
@@ -1,14 +1,20 @@
+pub mod bench;
 pub mod proxies;
 pub mod serializer;
 pub mod sign;
 
+use crate::bench::benchmark_decode;
 use crate::proxies::abi::{PyShipABI, PyABI};
+use crate::proxies::abi_store::PyAbiStore;
 use crate::proxies::checksums::{PyChecksum160, PyChecksum256, PyChecksum512};
+use crate::proxies::decoder::PyDecoder;
 use crate::proxies::private_key::PyPrivateKey;
 use crate::proxies::public_key::PyPublicKey;
 use crate::proxies::signature::PySignature;
-use crate::proxies::{asset::{PyAsset, PyExtendedAsset}, name::PyName, sym::PySymbol, sym_code::PySymbolCode};
-use crate::sign::sign_tx;
+use crate::proxies::{asset::{sum_assets, PyAsset, PyExtendedAsset}, name::PyName, sym::PySymbol, sym_code::PySymbolCode};
+use crate::sign::{
+    build_transaction, check_transaction_authorization, recover_signers, sign_tx, verify_batch,
+};
 use antelope::chain::abi::BUILTIN_TYPES;
 use proxies::time::{PyBlockTimestamp, PyTimePoint, PyTimePointSec};
 use pyo3::panic::PanicException;
@@ -30,6 +36,16 @@ fn antelope_rs(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // tx sign helper
     m.add_function(wrap_pyfunction!(sign_tx, m)?)?;
+    m.add_function(wrap_pyfunction!(build_transaction, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(recover_signers, m)?)?;
+    m.add_function(wrap_pyfunction!(check_transaction_authorization, m)?)?;
+
+    // asset helpers
+    m.add_function(wrap_pyfunction!(sum_assets, m)?)?;
+
+    // perf harness
+    m.add_function(wrap_pyfunction!(benchmark_decode, m)?)?;
 
     // proxy classes
     m.add_class::<PyName>()?;
@@ -53,6 +69,8 @@ fn antelope_rs(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<PyABI>()?;
     m.add_class::<PyShipABI>()?;
+    m.add_class::<PyAbiStore>()?;
+    m.add_class::<PyDecoder>()?;
 
     m.add("PanicException", py.get_type::<PanicException>())?;
 
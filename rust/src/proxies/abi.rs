@@ -1,12 +1,17 @@
-use crate::serializer::{decode::decode_abi_type, encode::encode_abi_type};
+use crate::proxies::checksums::PyChecksum256;
+use crate::serializer::{
+    decode::{decode_abi_type_with_options, DecodeOptions, DEFAULT_MAX_DEPTH},
+    encode::encode_abi_type,
+};
 use antelope::chain::abi::{
-    ABITypeResolver, AbiStruct, AbiTableView, AbiVariant, ShipABI, ABI,
+    ABITypeResolver, AbiStruct, AbiTableView, AbiVariant, ShipABI, TypeModifier, ABI,
 };
 use antelope::serializer::{Decoder, Encoder, Packer};
 use pyo3::basic::CompareOp;
-use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use pyo3::IntoPyObjectExt;
 use serde::ser::Serialize;
 use serde_json::Serializer;
 
@@ -32,6 +37,229 @@ fn abi_variant_as_dict<'py>(py: Python<'py>, v: &AbiVariant) -> PyResult<Bound<'
     Ok(d)
 }
 
+/// Names only in `old` & names only in `new`, keeping declaration order.
+fn names_diff(old: &[String], new: &[String]) -> (Vec<String>, Vec<String>) {
+    let added = new.iter().filter(|n| !old.contains(n)).cloned().collect();
+    let removed = old.iter().filter(|n| !new.contains(n)).cloned().collect();
+    (added, removed)
+}
+
+/// Field level changes of a struct present in both ABIs, `None` if equal.
+fn struct_diff<'py>(
+    py: Python<'py>,
+    old: &AbiStruct,
+    new: &AbiStruct,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let field_names = |s: &AbiStruct| s.fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+    let (added, removed) = names_diff(&field_names(old), &field_names(new));
+
+    let changed = PyDict::new(py);
+    for field in old.fields.iter() {
+        if let Some(new_field) = new.fields.iter().find(|f| f.name == field.name) {
+            if new_field.r#type != field.r#type {
+                changed.set_item(&field.name, (&field.r#type, &new_field.r#type))?;
+            }
+        }
+    }
+
+    let base = (old.base != new.base).then(|| (old.base.clone(), new.base.clone()));
+
+    if added.is_empty() && removed.is_empty() && changed.is_empty() && base.is_none() {
+        return Ok(None);
+    }
+
+    let d = PyDict::new(py);
+    d.set_item("added_fields", added)?;
+    d.set_item("removed_fields", removed)?;
+    d.set_item("changed_fields", changed)?;
+    d.set_item("base", base)?;
+    Ok(Some(d))
+}
+
+/// Convert plain Python data (dicts, lists, strs, ints, floats, bools &
+/// `None`) into a JSON value.
+fn py_to_json(obj: &Bound<PyAny>) -> PyResult<serde_json::Value> {
+    use serde_json::Value;
+
+    if obj.is_none() {
+        return Ok(Value::Null);
+    }
+    if let Ok(b) = obj.downcast::<PyBool>() {
+        return Ok(Value::Bool(b.is_true()));
+    }
+    if obj.is_instance_of::<PyInt>() {
+        if let Ok(i) = obj.extract::<i64>() {
+            return Ok(i.into());
+        }
+        return Ok(obj.extract::<u64>()?.into());
+    }
+    if let Ok(f) = obj.downcast::<PyFloat>() {
+        return Ok(f.value().into());
+    }
+    if let Ok(s) = obj.downcast::<PyString>() {
+        return Ok(Value::String(s.to_cow()?.into_owned()));
+    }
+    if let Ok(d) = obj.downcast::<PyDict>() {
+        let mut map = serde_json::Map::with_capacity(d.len());
+        for (k, v) in d.iter() {
+            let key: String = k
+                .extract()
+                .map_err(|_| PyValueError::new_err(format!("Non string key {k}")))?;
+            map.insert(key, py_to_json(&v)?);
+        }
+        return Ok(Value::Object(map));
+    }
+    if obj.is_instance_of::<PyList>() || obj.is_instance_of::<PyTuple>() {
+        let mut items = Vec::new();
+        for item in obj.try_iter()? {
+            items.push(py_to_json(&item?)?);
+        }
+        return Ok(Value::Array(items));
+    }
+    Err(PyValueError::new_err(format!(
+        "Can't convert {} to JSON",
+        obj.get_type().name()?
+    )))
+}
+
+/// First top-level key of `value` that fails to deserialize on its own, by
+/// swapping it into an otherwise default `T`.
+fn offending_key<T>(value: &serde_json::Value) -> Option<String>
+where
+    T: Default + Serialize + serde::de::DeserializeOwned,
+{
+    let serde_json::Value::Object(map) = value else {
+        return None;
+    };
+    let Ok(serde_json::Value::Object(base)) = serde_json::to_value(T::default()) else {
+        return None;
+    };
+    map.iter()
+        .find(|(k, v)| {
+            let mut probe = base.clone();
+            probe.insert((*k).clone(), (*v).clone());
+            serde_json::from_value::<T>(serde_json::Value::Object(probe)).is_err()
+        })
+        .map(|(k, _)| k.clone())
+}
+
+/// Inverse of `py_to_json`, numbers become `int` when integral.
+fn json_to_py<'py>(py: Python<'py>, value: &serde_json::Value) -> PyResult<Bound<'py, PyAny>> {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => Ok(py.None().into_bound(py)),
+        Value::Bool(b) => b.into_bound_py_any(py),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => i.into_bound_py_any(py),
+            (_, Some(u)) => u.into_bound_py_any(py),
+            _ => n.as_f64().into_bound_py_any(py),
+        },
+        Value::String(s) => s.into_bound_py_any(py),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(json_to_py(py, item)?)?;
+            }
+            Ok(list.into_any())
+        }
+        Value::Object(map) => {
+            let d = PyDict::new(py);
+            for (k, v) in map {
+                d.set_item(k, json_to_py(py, v)?)?;
+            }
+            Ok(d.into_any())
+        }
+    }
+}
+
+/// Append the entries of `from` missing in `into`, identical duplicates are
+/// skipped, same name with a different definition is an error.
+fn merge_named<T: Clone + PartialEq>(
+    kind: &str,
+    into: &mut Vec<T>,
+    from: &[T],
+    name: impl Fn(&T) -> String,
+) -> PyResult<()> {
+    for entry in from {
+        let entry_name = name(entry);
+        match into.iter().find(|e| name(e) == entry_name) {
+            Some(existing) if existing == entry => {}
+            Some(_) => {
+                return Err(PyValueError::new_err(format!(
+                    "Conflicting definitions for {kind} `{entry_name}`"
+                )))
+            }
+            None => into.push(entry.clone()),
+        }
+    }
+    Ok(())
+}
+
+/// Default nesting limit for `resolve_type_deep`.
+const DEFAULT_MAX_RESOLVE_DEPTH: usize = 32;
+
+/// Resolve `t` & recursively every type it references, struct bases &
+/// fields and variant members, into nested dicts.
+fn resolve_type_deep_dict<'py>(
+    py: Python<'py>,
+    abi: &impl ABITypeResolver,
+    t: &str,
+    depth: usize,
+    max_depth: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    if depth > max_depth {
+        return Err(PyValueError::new_err(format!(
+            "max resolve depth of {max_depth} exceeded at `{t}`"
+        )));
+    }
+
+    let res = abi
+        .resolve_type(t)
+        .map_err(|e| PyTypeError::new_err(e.to_string()))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("original_name", &res.original_name)?;
+    dict.set_item("resolved_name", &res.resolved_name)?;
+    dict.set_item(
+        "modifiers",
+        PyList::new(py, res.modifiers.iter().map(|tm| tm.as_str()))?,
+    )?;
+
+    if let Some(def) = &res.is_struct {
+        dict.set_item("kind", "struct")?;
+        let base = if def.base.is_empty() {
+            None
+        } else {
+            Some(resolve_type_deep_dict(py, abi, &def.base, depth + 1, max_depth)?)
+        };
+        dict.set_item("base", base)?;
+
+        let mut fields = Vec::with_capacity(def.fields.len());
+        for field in def.fields.iter() {
+            let f = PyDict::new(py);
+            f.set_item("name", &field.name)?;
+            f.set_item(
+                "type",
+                resolve_type_deep_dict(py, abi, &field.r#type, depth + 1, max_depth)?,
+            )?;
+            fields.push(f);
+        }
+        dict.set_item("fields", fields)?;
+    } else if let Some(def) = &res.is_variant {
+        dict.set_item("kind", "variant")?;
+        let mut types = Vec::with_capacity(def.types.len());
+        for member in def.types.iter() {
+            types.push(resolve_type_deep_dict(py, abi, member, depth + 1, max_depth)?);
+        }
+        dict.set_item("types", types)?;
+    } else {
+        dict.set_item("kind", "std")?;
+    }
+
+    Ok(dict)
+}
+
 macro_rules! define_pyabi {
     ($wrapper:ident, $pyname:literal, $inner:path) => {
         #[pyclass(frozen, name = $pyname)]
@@ -60,6 +288,22 @@ macro_rules! define_pyabi {
                 Ok(Self { inner })
             }
 
+            /// Build from the standard ABI dict shape, as returned by chain
+            /// RPC `get_abi`, without a JSON string round trip.
+            #[staticmethod]
+            pub fn from_dict(d: &Bound<PyDict>) -> PyResult<Self> {
+                let value = py_to_json(d.as_any())?;
+                let inner = <$inner as serde::Deserialize>::deserialize(&value).map_err(|e| {
+                    match offending_key::<$inner>(&value) {
+                        Some(key) => {
+                            PyValueError::new_err(format!("Invalid ABI dict, bad `{key}`: {e}"))
+                        }
+                        None => PyValueError::new_err(format!("Invalid ABI dict: {e}")),
+                    }
+                })?;
+                Ok(Self { inner })
+            }
+
             #[getter]
             pub fn version(&self) -> &String {
                 &self.inner.version
@@ -123,6 +367,167 @@ macro_rules! define_pyabi {
                 Ok(ret)
             }
 
+            pub fn action_names(&self) -> Vec<String> {
+                self.inner.actions.iter().map(|a| a.name.to_string()).collect()
+            }
+
+            pub fn struct_names(&self) -> Vec<String> {
+                self.inner.structs.iter().map(|s| s.name.clone()).collect()
+            }
+
+            pub fn table_names(&self) -> Vec<String> {
+                self.inner.tables.iter().map(|t| t.name_str()).collect()
+            }
+
+            pub fn variant_names(&self) -> Vec<String> {
+                self.inner.variants.iter().map(|v| v.name.clone()).collect()
+            }
+
+            pub fn has_action(&self, name: &str) -> bool {
+                self.inner.actions.iter().any(|a| a.name.to_string() == name)
+            }
+
+            pub fn has_table(&self, name: &str) -> bool {
+                self.inner.tables.iter().any(|t| t.name_str() == name)
+            }
+
+            pub fn has_struct(&self, name: &str) -> bool {
+                self.inner.structs.iter().any(|s| s.name == name)
+            }
+
+            /// Union of both ABIs' types, structs, variants, actions &
+            /// tables, raises `ValueError` if a name is defined differently in
+            /// each, the rest of the definition comes from `self`.
+            pub fn merge(&self, other: &Self) -> PyResult<Self> {
+                let mut inner = self.inner.clone();
+                merge_named("type", &mut inner.types, &other.inner.types, |t| {
+                    t.new_type_name.clone()
+                })?;
+                merge_named("struct", &mut inner.structs, &other.inner.structs, |s| {
+                    s.name.clone()
+                })?;
+                merge_named("variant", &mut inner.variants, &other.inner.variants, |v| {
+                    v.name.clone()
+                })?;
+                merge_named("action", &mut inner.actions, &other.inner.actions, |a| {
+                    a.name.to_string()
+                })?;
+                merge_named("table", &mut inner.tables, &other.inner.tables, |t| t.name_str())?;
+                Ok(Self { inner })
+            }
+
+            /// Check every type referenced by structs, variants, actions,
+            /// tables & aliases resolves, raises `ValueError` listing all the
+            /// unresolved ones.
+            pub fn validate(&self) -> PyResult<()> {
+                let mut refs: Vec<&str> = Vec::new();
+                for t in self.inner.types.iter() {
+                    refs.push(&t.r#type);
+                }
+                for s in self.inner.structs.iter() {
+                    if !s.base.is_empty() {
+                        refs.push(&s.base);
+                    }
+                    refs.extend(s.fields.iter().map(|f| f.r#type.as_str()));
+                }
+                for v in self.inner.variants.iter() {
+                    refs.extend(v.types.iter().map(|t| t.as_str()));
+                }
+                for a in self.inner.actions.iter() {
+                    refs.push(&a.r#type);
+                }
+                let table_types: Vec<String> =
+                    self.inner.tables.iter().map(|t| t.type_str()).collect();
+                refs.extend(table_types.iter().map(|t| t.as_str()));
+
+                let mut unresolved: Vec<&str> = Vec::new();
+                for t in refs {
+                    if !unresolved.contains(&t) && self.inner.resolve_type(t).is_err() {
+                        unresolved.push(t);
+                    }
+                }
+
+                if !unresolved.is_empty() {
+                    return Err(PyValueError::new_err(format!(
+                        "Unresolved types in ABI: {}",
+                        unresolved.join(", ")
+                    )));
+                }
+                Ok(())
+            }
+
+            /// Struct type of action `name`, raises `KeyError` if undefined.
+            pub fn get_action_type(&self, name: &str) -> PyResult<String> {
+                self.inner
+                    .actions
+                    .iter()
+                    .find(|a| a.name.to_string() == name)
+                    .map(|a| a.r#type.clone())
+                    .ok_or_else(|| PyKeyError::new_err(format!("Action {name} not in ABI")))
+            }
+
+            /// Row type of table `name`, raises `KeyError` if undefined.
+            pub fn get_table_type(&self, name: &str) -> PyResult<String> {
+                self.inner
+                    .tables
+                    .iter()
+                    .find(|t| t.name_str() == name)
+                    .map(|t| t.type_str())
+                    .ok_or_else(|| PyKeyError::new_err(format!("Table {name} not in ABI")))
+            }
+
+            /// True if `name` is declared as a type alias.
+            pub fn has_type(&self, name: &str) -> bool {
+                self.inner.types.iter().any(|t| t.new_type_name == name)
+            }
+
+            /// Name based summary of what changed from `self` to `other`:
+            /// `added_*`/`removed_*` name lists for structs, actions, tables,
+            /// variants & types, plus `changed_structs` mapping struct name to
+            /// its `added_fields`, `removed_fields`, `changed_fields`
+            /// (`{field: (old_type, new_type)}`) & `base` (`(old, new)` or
+            /// `None`).
+            pub fn diff<'py>(&self, py: Python<'py>, other: &Self) -> PyResult<Bound<'py, PyDict>> {
+                let d = PyDict::new(py);
+
+                let (added, removed) = names_diff(&self.struct_names(), &other.struct_names());
+                d.set_item("added_structs", added)?;
+                d.set_item("removed_structs", removed)?;
+
+                let changed = PyDict::new(py);
+                for old in self.inner.structs.iter() {
+                    let Some(new) = other.inner.structs.iter().find(|s| s.name == old.name) else {
+                        continue;
+                    };
+                    if let Some(sd) = struct_diff(py, old, new)? {
+                        changed.set_item(&old.name, sd)?;
+                    }
+                }
+                d.set_item("changed_structs", changed)?;
+
+                let (added, removed) = names_diff(&self.action_names(), &other.action_names());
+                d.set_item("added_actions", added)?;
+                d.set_item("removed_actions", removed)?;
+
+                let (added, removed) = names_diff(&self.table_names(), &other.table_names());
+                d.set_item("added_tables", added)?;
+                d.set_item("removed_tables", removed)?;
+
+                let (added, removed) = names_diff(&self.variant_names(), &other.variant_names());
+                d.set_item("added_variants", added)?;
+                d.set_item("removed_variants", removed)?;
+
+                let type_names = |abi: &$inner| {
+                    abi.types.iter().map(|t| t.new_type_name.clone()).collect::<Vec<_>>()
+                };
+                let (added, removed) =
+                    names_diff(&type_names(&self.inner), &type_names(&other.inner));
+                d.set_item("added_types", added)?;
+                d.set_item("removed_types", removed)?;
+
+                Ok(d)
+            }
+
             pub fn resolve_type_into_dict<'py>(
                 &self,
                 py: Python<'py>,
@@ -159,20 +564,94 @@ macro_rules! define_pyabi {
                 Ok(dict)
             }
 
+            /// Like `resolve_type_into_dict` but expanded all the way down:
+            /// every node has `original_name`, `resolved_name`, `modifiers` &
+            /// `kind` (`std`, `struct` or `variant`), structs add `base` &
+            /// `fields` (`{name, type}` with `type` a node), variants add
+            /// `types`. Raises `ValueError` past `max_depth` levels, which
+            /// self referential types always reach.
+            #[pyo3(signature = (t, max_depth=DEFAULT_MAX_RESOLVE_DEPTH))]
+            pub fn resolve_type_deep<'py>(
+                &self,
+                py: Python<'py>,
+                t: &str,
+                max_depth: usize,
+            ) -> PyResult<Bound<'py, PyDict>> {
+                resolve_type_deep_dict(py, &self.inner, t, 0, max_depth)
+            }
+
+            /// Fully resolve `t` peeling aliases & modifiers, returns
+            /// `(builtin, is_array, is_optional, is_extension)`, for struct &
+            /// variant types `builtin` is the struct/variant name.
+            pub fn terminal_type(&self, t: &str) -> PyResult<(String, bool, bool, bool)> {
+                let res = self
+                    .inner
+                    .resolve_type(t)
+                    .map_err(|e| PyTypeError::new_err(e.to_string()))?;
+
+                let mods = &res.modifiers;
+                Ok((
+                    res.resolved_name.clone(),
+                    mods.iter().any(|m| matches!(m, TypeModifier::Array)),
+                    mods.iter().any(|m| matches!(m, TypeModifier::Optional)),
+                    mods.iter().any(|m| matches!(m, TypeModifier::Extension)),
+                ))
+            }
+
             pub fn pack<'py>(&self, t: &str, val: &Bound<'py, PyAny>) -> PyResult<Vec<u8>> {
                 let mut encoder = Encoder::new(0);
                 encode_abi_type(&self.inner, t, val, &mut encoder)?;
                 Ok(encoder.get_bytes().to_vec())
             }
 
+            /// Pack every value in `vals` as `t` back to back into a single
+            /// buffer, returns it along with the `len(vals) + 1` boundaries,
+            /// value `i` is `buf[offsets[i]:offsets[i + 1]]`.
+            pub fn pack_batch<'py>(
+                &self,
+                t: &str,
+                vals: Vec<Bound<'py, PyAny>>,
+            ) -> PyResult<(Vec<u8>, Vec<usize>)> {
+                let mut encoder = Encoder::new(0);
+                let mut offsets = Vec::with_capacity(vals.len() + 1);
+                offsets.push(0);
+                for val in vals.iter() {
+                    encode_abi_type(&self.inner, t, val, &mut encoder)?;
+                    offsets.push(encoder.get_bytes().len());
+                }
+                Ok((encoder.get_bytes().to_vec(), offsets))
+            }
+
+            /// `typed=False` returns keys, signatures & checksums as strings,
+            /// `strict=True` rejects bytes left over after the value.
+            #[pyo3(signature = (
+                t, buf, max_depth=DEFAULT_MAX_DEPTH, max_output=None, typed=true, strict=false
+            ))]
+            #[allow(clippy::too_many_arguments)]
             pub fn unpack<'py>(
                 &self,
                 py: Python<'py>,
                 t: &str,
                 buf: &[u8],
+                max_depth: usize,
+                max_output: Option<usize>,
+                typed: bool,
+                strict: bool,
             ) -> PyResult<Bound<'py, PyAny>> {
                 let mut decoder = Decoder::new(buf);
-                decode_abi_type(py, &self.inner, t, &mut decoder)
+                let opts = DecodeOptions {
+                    max_depth,
+                    max_output,
+                    typed,
+                };
+                let val = decode_abi_type_with_options(py, &self.inner, t, &mut decoder, &opts)?;
+                if strict && decoder.remaining() > 0 {
+                    return Err(PyValueError::new_err(format!(
+                        "{} trailing bytes after decoding `{t}`",
+                        decoder.remaining()
+                    )));
+                }
+                Ok(val)
             }
 
             pub fn to_string(&self) -> String {
@@ -189,6 +668,17 @@ macro_rules! define_pyabi {
                 encoder.get_bytes().to_vec()
             }
 
+            /// sha256 over the binary encoding, stable across processes.
+            pub fn compute_hash(&self) -> PyResult<PyChecksum256> {
+                PyChecksum256::hash(&self.encode())
+            }
+
+            pub fn to_json_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                let value = serde_json::to_value(&self.inner)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                json_to_py(py, &value)
+            }
+
             fn __str__(&self) -> String {
                 self.to_string()
             }
@@ -208,3 +698,10 @@ macro_rules! define_pyabi {
 
 define_pyabi!(PyABI, "ABI", ABI);
 define_pyabi!(PyShipABI, "ShipABI", ShipABI);
+
+/// Borrow of either ABI flavour, for functions that accept both.
+#[derive(FromPyObject)]
+pub enum ABIRef<'py> {
+    Std(PyRef<'py, PyABI>),
+    Ship(PyRef<'py, PyShipABI>),
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use antelope::serializer::Decoder;
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::proxies::abi::PyABI;
+use crate::serializer::decode::decode_abi_type;
+
+#[derive(FromPyObject)]
+pub enum ABILike<'py> {
+    Cls(Py<PyABI>),
+    Raw(Vec<u8>),
+    Str(String),
+    Dict(Bound<'py, PyDict>),
+}
+
+/// Account name to ABI registry, each instance is isolated so the same
+/// account can map to different ABIs in different stores (e.g. testnet vs
+/// mainnet).
+#[pyclass(name = "AbiStore")]
+#[derive(Default)]
+pub struct PyAbiStore {
+    abis: HashMap<String, Py<PyABI>>,
+}
+
+impl PyAbiStore {
+    pub(crate) fn abi<'py>(
+        &self,
+        py: Python<'py>,
+        account: &str,
+    ) -> PyResult<&Bound<'py, PyABI>> {
+        self.abis
+            .get(account)
+            .map(|abi| abi.bind(py))
+            .ok_or_else(|| PyKeyError::new_err(format!("No ABI loaded for `{account}`")))
+    }
+}
+
+#[pymethods]
+impl PyAbiStore {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `abi` for `account`, replacing any previous one. Accepts an
+    /// `ABI`, its JSON as str or dict, or the packed binary format.
+    pub fn load(&mut self, py: Python<'_>, account: &str, abi: ABILike) -> PyResult<()> {
+        let abi = match abi {
+            ABILike::Cls(abi) => abi,
+            ABILike::Raw(raw) => Py::new(py, PyABI::from_bytes(&raw)?)?,
+            ABILike::Str(s) => Py::new(py, PyABI::from_str_py(&s)?)?,
+            ABILike::Dict(d) => Py::new(py, PyABI::from_dict(&d)?)?,
+        };
+        self.abis.insert(account.to_string(), abi);
+        Ok(())
+    }
+
+    /// Drop the ABI of `account`, returns whether there was one.
+    pub fn unload(&mut self, account: &str) -> bool {
+        self.abis.remove(account).is_some()
+    }
+
+    pub fn get(&self, py: Python<'_>, account: &str) -> PyResult<Py<PyABI>> {
+        self.abi(py, account).map(|abi| abi.clone().unbind())
+    }
+
+    /// Loaded account names, sorted.
+    pub fn list(&self) -> Vec<String> {
+        let mut accounts: Vec<String> = self.abis.keys().cloned().collect();
+        accounts.sort();
+        accounts
+    }
+
+    pub fn pack(
+        &self,
+        py: Python<'_>,
+        account: &str,
+        t: &str,
+        val: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<u8>> {
+        self.abi(py, account)?.get().pack(t, val)
+    }
+
+    /// Decode with default options, use `get(account).unpack(...)` to tune
+    /// them.
+    pub fn unpack<'py>(
+        &self,
+        py: Python<'py>,
+        account: &str,
+        t: &str,
+        buf: &[u8],
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let abi = self.abi(py, account)?;
+        let mut decoder = Decoder::new(buf);
+        decode_abi_type(py, &abi.get().inner, t, &mut decoder)
+    }
+
+    fn __contains__(&self, account: &str) -> bool {
+        self.abis.contains_key(account)
+    }
+
+    fn __len__(&self) -> usize {
+        self.abis.len()
+    }
+}
@@ -1,18 +1,33 @@
-use antelope::chain::asset::{Asset, ExtendedAsset};
+use antelope::chain::asset::{Asset, ExtendedAsset, ASSET_MAX_AMOUNT};
 use antelope::serializer::{Decoder, Encoder, Packer};
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::{PyKeyError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::fmt::Display;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 
 use crate::proxies::{
     name::{PyName, NameLike},
     sym::{PySymbol, SymLike},
+    sym_code::PySymbolCode,
 };
 
+/// Amounts in scientific notation (`1e3 EOS`, `1E+5 EOS`) are ambiguous
+/// regarding precision, reject them with a clear error instead of the
+/// parser's.
+fn reject_scientific(s: &str) -> PyResult<()> {
+    let amount = s.split_whitespace().next().unwrap_or_default();
+    if amount.contains(['e', 'E']) {
+        return Err(PyValueError::new_err(format!(
+            "scientific notation not supported; use plain decimal: {s:?}"
+        )));
+    }
+    Ok(())
+}
+
 #[pyclass(frozen, name = "Asset")]
 #[derive(Debug, Clone)]
 pub struct PyAsset {
@@ -63,18 +78,36 @@ impl PyAsset {
 
     #[staticmethod]
     pub fn from_str_py(s: &str) -> PyResult<Self> {
+        reject_scientific(s)?;
         Asset::from_str(s)
             .map(|a| a.into())
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    /// Build from a raw amount plus the symbol's precision and code.
+    #[staticmethod]
+    pub fn from_ints(amount: i64, precision: u8, code: &str) -> PyResult<Self> {
+        let sym = PySymbol::from_parts(code, precision)?;
+        PyAsset::new(amount, SymLike::Cls(sym))
+    }
+
+    /// Scale `d` to exactly the symbol's precision, extra fractional digits
+    /// are rounded half away from zero.
     #[staticmethod]
     pub fn from_decimal(d: Decimal, sym: SymLike) -> PyResult<Self> {
         let sym = PySymbol::try_from(sym)?;
+        let precision = sym.precision() as u32;
 
-        let d_str = d.to_string().replace(".", "");
-        let amount = i64::from_str(&d_str)
-            .map_err(|e| PyValueError::new_err(format!("Decimal not valid i64: {e}")))?;
+        let mut scaled =
+            d.round_dp_with_strategy(precision, RoundingStrategy::MidpointAwayFromZero);
+        scaled.rescale(precision);
+
+        let amount = i64::try_from(scaled.mantissa())
+            .ok()
+            .filter(|a| a.abs() <= ASSET_MAX_AMOUNT)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!("Decimal {d} out of asset amount range"))
+            })?;
 
         PyAsset::new(amount, SymLike::Cls(sym))
     }
@@ -143,17 +176,95 @@ impl PyAsset {
         }
     }
 
+    #[getter]
+    pub fn precision(&self) -> u8 {
+        self.inner.symbol().precision()
+    }
+
+    #[getter]
+    pub fn symbol_code(&self) -> PySymbolCode {
+        PySymbolCode {
+            inner: self.inner.symbol().code(),
+        }
+    }
+
+    /// Numeric part of the asset formatted with its precision, no symbol code.
+    pub fn to_amount_string(&self) -> String {
+        let precision = self.precision() as usize;
+        let mut digits = format!(
+            "{:0>width$}",
+            self.amount().unsigned_abs(),
+            width = precision + 1
+        );
+        if precision > 0 {
+            digits.insert(digits.len() - precision, '.');
+        }
+        if self.amount() < 0 {
+            digits.insert(0, '-');
+        }
+        digits
+    }
+
+    /// Inverse of `from_dict`.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let d = PyDict::new(py);
+        d.set_item("amount", self.amount())?;
+        d.set_item("symbol", self.inner.symbol().to_string())?;
+        Ok(d)
+    }
+
+    /// Attach a token contract to this asset.
+    pub fn to_extended(&self, contract: NameLike) -> PyResult<PyExtendedAsset> {
+        let contract = PyName::try_from(contract)?;
+        Ok(ExtendedAsset {
+            quantity: self.inner,
+            contract: contract.inner,
+        }
+        .into())
+    }
+
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
+    /// Compare decimal values of two assets sharing a symbol code, ignoring
+    /// precision, so `1.0 EOS` equals `1.0000 EOS`.
+    pub fn equals_value(&self, other: &PyAsset) -> bool {
+        let (sym, other_sym) = (self.inner.symbol(), other.inner.symbol());
+        sym.code() == other_sym.code()
+            && Decimal::new(self.amount(), sym.precision() as u32)
+                == Decimal::new(other.amount(), other_sym.precision() as u32)
+    }
+
+    /// Consistent with `==`, hashes `(amount, symbol.value())`.
+    fn __hash__(&self) -> u64 {
+        let mut h = DefaultHasher::new();
+        (self.amount(), self.inner.symbol().value()).hash(&mut h);
+        h.finish()
+    }
+
+    /// Strict equality, amount and full symbol (code & precision) must match,
+    /// see `equals_value` for precision agnostic comparison.
+    ///
+    /// Ordering compares amounts and is only allowed between assets with the
+    /// same symbol.
     fn __richcmp__(&self, other: PyRef<PyAsset>, op: CompareOp) -> PyResult<bool> {
         match op {
             CompareOp::Eq => Ok(self.inner == other.inner),
             CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
+            _ => {
+                let (sym, other_sym) = (self.inner.symbol(), other.inner.symbol());
+                if sym != other_sym {
+                    return Err(PyValueError::new_err(format!(
+                        "cannot compare assets with different symbols: {sym} and {other_sym}"
+                    )));
+                }
+                Ok(op.matches(self.amount().cmp(&other.amount())))
+            }
         }
     }
 
@@ -172,6 +283,48 @@ impl PyAsset {
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyAsset { inner: result })
     }
+
+    fn __neg__(&self) -> PyResult<PyAsset> {
+        let amount = self
+            .amount()
+            .checked_neg()
+            .ok_or_else(|| PyValueError::new_err("Asset amount overflow on negation"))?;
+        PyAsset::new(amount, SymLike::Cls(self.symbol()))
+    }
+
+    fn __abs__(&self) -> PyResult<PyAsset> {
+        let amount = self
+            .amount()
+            .checked_abs()
+            .ok_or_else(|| PyValueError::new_err("Asset amount overflow on abs"))?;
+        PyAsset::new(amount, SymLike::Cls(self.symbol()))
+    }
+}
+
+/// Sum all `assets` in a single pass, they must all share the first asset's
+/// symbol.
+#[pyfunction]
+pub fn sum_assets(assets: Vec<PyAsset>) -> PyResult<PyAsset> {
+    let first = assets
+        .first()
+        .ok_or_else(|| PyValueError::new_err("Can't sum an empty list of assets"))?;
+    let sym = first.inner.symbol();
+
+    let mut total: i64 = 0;
+    for (i, asset) in assets.iter().enumerate() {
+        if asset.inner.symbol() != sym {
+            return Err(PyValueError::new_err(format!(
+                "Asset at index {i} has symbol {} expected {sym}",
+                asset.inner.symbol()
+            )));
+        }
+        total = total
+            .checked_add(asset.amount())
+            .filter(|t| t.abs() <= ASSET_MAX_AMOUNT)
+            .ok_or_else(|| PyValueError::new_err(format!("Asset sum overflow at index {i}")))?;
+    }
+
+    PyAsset::new(total, SymLike::Cls(first.symbol()))
 }
 
 impl Display for PyAsset {
@@ -224,6 +377,7 @@ impl PyExtendedAsset {
     #[staticmethod]
     #[pyo3(name = "from_str")]
     pub fn from_str_py(s: &str) -> PyResult<Self> {
+        reject_scientific(s)?;
         let ext = ExtendedAsset::from_str(s).map_err(|e| PyValueError::new_err(e.to_string()))?;
 
         Ok(ext.into())
@@ -264,17 +418,79 @@ impl PyExtendedAsset {
         }
     }
 
+    #[getter]
+    pub fn quantity(&self) -> PyAsset {
+        PyAsset::from(self.inner.quantity)
+    }
+
+    #[getter]
+    pub fn contract(&self) -> PyName {
+        PyName {
+            inner: self.inner.contract,
+        }
+    }
+
     pub fn encode(&self) -> Vec<u8> {
         let mut encoder = Encoder::new(0);
         self.inner.pack(&mut encoder);
         encoder.get_bytes().to_vec()
     }
 
+    /// Inverse of `from_dict`.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let d = PyDict::new(py);
+        d.set_item("quantity", PyAsset::from(self.inner.quantity).to_dict(py)?)?;
+        d.set_item("contract", self.inner.contract.to_string())?;
+        Ok(d)
+    }
+
+    /// Same shape as the chain's JSON representation
+    pub fn to_json_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let d = PyDict::new(py);
+        d.set_item("quantity", self.inner.quantity.to_string())?;
+        d.set_item("contract", self.inner.contract.to_string())?;
+        Ok(d)
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
+    /// Consistent with `==`, hashes `(amount, symbol.value(), contract.value())`.
+    fn __hash__(&self) -> u64 {
+        let mut h = DefaultHasher::new();
+        (
+            self.inner.quantity.amount(),
+            self.inner.quantity.symbol().value(),
+            self.inner.contract.value(),
+        )
+            .hash(&mut h);
+        h.finish()
+    }
+
+    /// Equality checks quantity & contract, ordering compares amounts and is
+    /// only allowed when both symbol and contract match.
+    fn __richcmp__(&self, other: PyRef<PyExtendedAsset>, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => Ok(self.inner == other.inner),
+            CompareOp::Ne => Ok(self.inner != other.inner),
+            _ => {
+                let (quantity, other_quantity) = (self.inner.quantity, other.inner.quantity);
+                if quantity.symbol() != other_quantity.symbol()
+                    || self.inner.contract != other.inner.contract
+                {
+                    return Err(PyValueError::new_err(format!(
+                        "cannot compare extended assets with different symbol or contract: {} and {}",
+                        self.inner, other.inner
+                    )));
+                }
+                Ok(op.matches(quantity.amount().cmp(&other_quantity.amount())))
+            }
+        }
+    }
+
     fn __add__(&self, other: &PyExtendedAsset) -> PyResult<PyExtendedAsset> {
+        self.check_same_contract(other)?;
         let result = self
             .inner
             .quantity
@@ -289,6 +505,7 @@ impl PyExtendedAsset {
     }
 
     fn __sub__(&self, other: &PyExtendedAsset) -> PyResult<PyExtendedAsset> {
+        self.check_same_contract(other)?;
         let result = self
             .inner
             .quantity
@@ -303,6 +520,18 @@ impl PyExtendedAsset {
     }
 }
 
+impl PyExtendedAsset {
+    fn check_same_contract(&self, other: &PyExtendedAsset) -> PyResult<()> {
+        if self.inner.contract != other.inner.contract {
+            return Err(PyValueError::new_err(format!(
+                "cannot operate on extended assets from different contracts: {} and {}",
+                self.inner.contract, other.inner.contract
+            )));
+        }
+        Ok(())
+    }
+}
+
 impl Display for PyExtendedAsset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.inner)
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 
 use antelope::chain::checksum::{
@@ -7,6 +8,18 @@ use antelope::chain::checksum::{
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+
+/// Hex decode `s`, which must encode exactly `N` bytes.
+fn decode_hex_exact<const N: usize>(s: &str) -> PyResult<[u8; N]> {
+    let raw =
+        hex::decode(s).map_err(|e| PyValueError::new_err(format!("Invalid hex {s:?}: {e}")))?;
+    let len = raw.len();
+    raw.try_into()
+        .map_err(|_| PyValueError::new_err(format!("Expected {N} bytes of hex, got {len}")))
+}
 
 #[pyclass(frozen, name = "Checksum160")]
 #[derive(Debug, Clone)]
@@ -48,6 +61,19 @@ impl PyChecksum160 {
             .map_err(|err| PyValueError::new_err(err.to_string()))
     }
 
+    /// Strict hex constructor, unlike `from_str` never tries base64.
+    #[staticmethod]
+    pub fn from_hex(s: &str) -> PyResult<Self> {
+        let data: [u8; 20] = decode_hex_exact(s)?;
+        PyChecksum160::from_bytes(data)
+    }
+
+    /// ripemd160 digest of `data`
+    #[staticmethod]
+    pub fn hash160(data: &[u8]) -> PyResult<Self> {
+        PyChecksum160::from_bytes(Ripemd160::digest(data).into())
+    }
+
     #[staticmethod]
     pub fn try_from(value: Sum160Like) -> PyResult<PyChecksum160> {
         match value {
@@ -62,18 +88,45 @@ impl PyChecksum160 {
         &self.inner.data
     }
 
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.inner.data)
+    }
+
+    #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        fill_readonly_buffer(slf.as_any(), &slf.get().inner.data, view, flags)
+    }
+
+    /// Nothing to free, the view borrows `inner.data`.
+    #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {}
+
+    /// Lowercase hex
+    pub fn hex(&self) -> String {
+        hex::encode(self.inner.data)
+    }
+
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
-    fn __richcmp__(&self, other: PyRef<PyChecksum160>, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    fn __hash__(&self) -> u64 {
+        let mut h = DefaultHasher::new();
+        self.inner.data.hash(&mut h);
+        h.finish()
+    }
+
+    /// Lexicographic byte order.
+    fn __richcmp__(&self, other: PyRef<PyChecksum160>, op: CompareOp) -> bool {
+        op.matches(self.inner.data.cmp(&other.inner.data))
     }
 }
 
@@ -83,6 +136,69 @@ impl Display for PyChecksum160 {
     }
 }
 
+/// Export `data`, owned by `obj`, as a readonly contiguous byte buffer. The
+/// limited API only has the buffer protocol from Python 3.11 on.
+#[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+unsafe fn fill_readonly_buffer(
+    obj: &Bound<'_, PyAny>,
+    data: &[u8],
+    view: *mut pyo3::ffi::Py_buffer,
+    flags: std::os::raw::c_int,
+) -> PyResult<()> {
+    use pyo3::exceptions::PyBufferError;
+    use pyo3::ffi;
+
+    if view.is_null() {
+        return Err(PyBufferError::new_err("View is null"));
+    }
+    if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+        return Err(PyBufferError::new_err("Checksums are read-only"));
+    }
+    // view keeps a reference to `obj`, frozen so `data` can't change under it
+    let ret = ffi::PyBuffer_FillInfo(
+        view,
+        obj.as_ptr(),
+        data.as_ptr() as *mut std::os::raw::c_void,
+        data.len() as ffi::Py_ssize_t,
+        1,
+        flags,
+    );
+    if ret == -1 {
+        return Err(PyErr::fetch(obj.py()));
+    }
+    Ok(())
+}
+
+/// Canonical EOSIO merkle root: pairs get their first bit cleared (left) or
+/// set (right) before hashing, odd levels duplicate their last node.
+fn canonical_merkle(mut ids: Vec<[u8; 32]>) -> [u8; 32] {
+    if ids.is_empty() {
+        return [0u8; 32];
+    }
+
+    while ids.len() > 1 {
+        if ids.len() % 2 == 1 {
+            ids.push(ids[ids.len() - 1]);
+        }
+
+        ids = ids
+            .chunks_exact(2)
+            .map(|pair| {
+                let (mut left, mut right) = (pair[0], pair[1]);
+                left[0] &= 0x7f;
+                right[0] |= 0x80;
+
+                let mut hasher = Sha256::new();
+                hasher.update(left);
+                hasher.update(right);
+                hasher.finalize().into()
+            })
+            .collect();
+    }
+
+    ids[0]
+}
+
 #[pyclass(frozen, name = "Checksum256")]
 #[derive(Debug, Clone)]
 pub struct PyChecksum256 {
@@ -123,6 +239,19 @@ impl PyChecksum256 {
             .map_err(|err| PyValueError::new_err(err.to_string()))
     }
 
+    /// Strict hex constructor, unlike `from_str` never tries base64.
+    #[staticmethod]
+    pub fn from_hex(s: &str) -> PyResult<Self> {
+        let data: [u8; 32] = decode_hex_exact(s)?;
+        PyChecksum256::from_bytes(data)
+    }
+
+    /// sha256 digest of `data`
+    #[staticmethod]
+    pub fn hash(data: &[u8]) -> PyResult<Self> {
+        PyChecksum256::from_bytes(Sha256::digest(data).into())
+    }
+
     #[staticmethod]
     pub fn try_from(value: Sum256Like) -> PyResult<PyChecksum256> {
         match value {
@@ -132,23 +261,61 @@ impl PyChecksum256 {
         }
     }
 
+    /// Merkle root over a complete list of leaf digests, an empty list yields
+    /// the all zero checksum.
+    #[staticmethod]
+    pub fn merkle_root(leaves: Vec<Sum256Like>) -> PyResult<PyChecksum256> {
+        let mut ids = Vec::with_capacity(leaves.len());
+        for leaf in leaves {
+            ids.push(PyChecksum256::try_from(leaf)?.inner.data);
+        }
+        PyChecksum256::from_bytes(canonical_merkle(ids))
+    }
+
     #[getter]
     pub fn raw(&self) -> &[u8; 32] {
         &self.inner.data
     }
 
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.inner.data)
+    }
+
+    #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        fill_readonly_buffer(slf.as_any(), &slf.get().inner.data, view, flags)
+    }
+
+    /// Nothing to free, the view borrows `inner.data`.
+    #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {}
+
+    /// Lowercase hex
+    pub fn hex(&self) -> String {
+        hex::encode(self.inner.data)
+    }
+
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
-    fn __richcmp__(&self, other: PyRef<PyChecksum256>, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    fn __hash__(&self) -> u64 {
+        let mut h = DefaultHasher::new();
+        self.inner.data.hash(&mut h);
+        h.finish()
+    }
+
+    /// Lexicographic byte order.
+    fn __richcmp__(&self, other: PyRef<PyChecksum256>, op: CompareOp) -> bool {
+        op.matches(self.inner.data.cmp(&other.inner.data))
     }
 }
 
@@ -198,6 +365,19 @@ impl PyChecksum512 {
             .map_err(|err| PyValueError::new_err(err.to_string()))
     }
 
+    /// Strict hex constructor, unlike `from_str` never tries base64.
+    #[staticmethod]
+    pub fn from_hex(s: &str) -> PyResult<Self> {
+        let data: [u8; 64] = decode_hex_exact(s)?;
+        PyChecksum512::from_bytes(data)
+    }
+
+    /// sha512 digest of `data`
+    #[staticmethod]
+    pub fn hash512(data: &[u8]) -> PyResult<Self> {
+        PyChecksum512::from_bytes(Sha512::digest(data).into())
+    }
+
     #[staticmethod]
     pub fn try_from(value: Sum512Like) -> PyResult<PyChecksum512> {
         match value {
@@ -212,18 +392,45 @@ impl PyChecksum512 {
         &self.inner.data
     }
 
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.inner.data)
+    }
+
+    #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: std::os::raw::c_int,
+    ) -> PyResult<()> {
+        fill_readonly_buffer(slf.as_any(), &slf.get().inner.data, view, flags)
+    }
+
+    /// Nothing to free, the view borrows `inner.data`.
+    #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {}
+
+    /// Lowercase hex
+    pub fn hex(&self) -> String {
+        hex::encode(self.inner.data)
+    }
+
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
-    fn __richcmp__(&self, other: PyRef<PyChecksum512>, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    fn __hash__(&self) -> u64 {
+        let mut h = DefaultHasher::new();
+        self.inner.data.hash(&mut h);
+        h.finish()
+    }
+
+    /// Lexicographic byte order.
+    fn __richcmp__(&self, other: PyRef<PyChecksum512>, op: CompareOp) -> bool {
+        op.matches(self.inner.data.cmp(&other.inner.data))
     }
 }
 
@@ -0,0 +1,167 @@
+use antelope::chain::abi::ABITypeResolver;
+use antelope::serializer::Decoder;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+use crate::proxies::abi::{ABIRef, PyABI, PyShipABI};
+use crate::serializer::decode::{decode_abi_type, skip_abi_type};
+
+/// Cursor over a buffer of packed ABI values, lets callers decode a stream of
+/// values one by one and skip the ones they don't care about.
+#[pyclass(name = "Decoder")]
+#[derive(Debug, Clone)]
+pub struct PyDecoder {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl PyDecoder {
+    /// Run `f` on a native decoder positioned at the cursor, only advance the
+    /// cursor if `f` succeeded.
+    fn advance<R>(&mut self, f: impl FnOnce(&mut Decoder<'_>) -> PyResult<R>) -> PyResult<R> {
+        let mut decoder = Decoder::new(&self.buf[self.pos..]);
+        let res = f(&mut decoder)?;
+        self.pos += decoder.get_pos();
+        Ok(res)
+    }
+}
+
+/// `(name, type)` of every field of struct `type_name`, base fields first.
+fn struct_fields<ABI: ABITypeResolver>(
+    abi: &ABI,
+    type_name: &str,
+) -> PyResult<Vec<(String, String)>> {
+    let meta = abi
+        .resolve_type(type_name)
+        .map_err(|e| PyTypeError::new_err(e.to_string()))?;
+
+    let struct_def = match meta.is_struct {
+        Some(def) if meta.modifiers.is_empty() => def,
+        _ => {
+            return Err(PyTypeError::new_err(format!(
+                "{type_name} is not a struct type"
+            )))
+        }
+    };
+
+    let mut fields = if struct_def.base.is_empty() {
+        Vec::new()
+    } else {
+        struct_fields(abi, &struct_def.base)?
+    };
+    fields.extend(
+        struct_def
+            .fields
+            .iter()
+            .map(|f| (f.name.clone(), f.r#type.clone())),
+    );
+    Ok(fields)
+}
+
+/// Owned handle to either ABI flavour, lets iterators outlive the call that
+/// created them.
+enum ABIHandle {
+    Std(Py<PyABI>),
+    Ship(Py<PyShipABI>),
+}
+
+impl ABIHandle {
+    fn borrow<'py>(&self, py: Python<'py>) -> ABIRef<'py> {
+        match self {
+            ABIHandle::Std(abi) => ABIRef::Std(abi.bind(py).borrow()),
+            ABIHandle::Ship(abi) => ABIRef::Ship(abi.bind(py).borrow()),
+        }
+    }
+}
+
+/// Iterator over the fields of a struct, decoding each one from the parent
+/// `Decoder` on demand.
+#[pyclass(name = "StructIter")]
+pub struct PyStructIter {
+    decoder: Py<PyDecoder>,
+    abi: ABIHandle,
+    fields: std::vec::IntoIter<(String, String)>,
+}
+
+#[pymethods]
+impl PyStructIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Option<(String, Bound<'py, PyAny>)>> {
+        let Some((name, type_name)) = self.fields.next() else {
+            return Ok(None);
+        };
+        let value = self
+            .decoder
+            .borrow_mut(py)
+            .unpack(py, self.abi.borrow(py), &type_name)?;
+        Ok(Some((name, value)))
+    }
+}
+
+#[pymethods]
+impl PyDecoder {
+    #[new]
+    fn new(buf: Vec<u8>) -> Self {
+        PyDecoder { buf, pos: 0 }
+    }
+
+    #[getter]
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[getter]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    pub fn unpack<'py>(
+        &mut self,
+        py: Python<'py>,
+        abi: ABIRef<'py>,
+        type_name: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.advance(|decoder| match abi {
+            ABIRef::Std(abi) => decode_abi_type(py, &abi.inner, type_name, decoder),
+            ABIRef::Ship(abi) => decode_abi_type(py, &abi.inner, type_name, decoder),
+        })
+    }
+
+    /// Advance past a value of `type_name` without building it, returns the
+    /// amount of bytes skipped.
+    pub fn skip<'py>(&mut self, abi: ABIRef<'py>, type_name: &str) -> PyResult<usize> {
+        let start = self.pos;
+        self.advance(|decoder| match abi {
+            ABIRef::Std(abi) => skip_abi_type(&abi.inner, type_name, decoder),
+            ABIRef::Ship(abi) => skip_abi_type(&abi.inner, type_name, decoder),
+        })?;
+        Ok(self.pos - start)
+    }
+
+    /// Lazily decode struct `type_name` as `(field_name, value)` pairs, each
+    /// field is read from this decoder when the iterator reaches it.
+    pub fn iter_struct<'py>(
+        slf: Bound<'py, Self>,
+        abi: ABIRef<'py>,
+        type_name: &str,
+    ) -> PyResult<PyStructIter> {
+        let (fields, abi) = match abi {
+            ABIRef::Std(abi) => (
+                struct_fields(&abi.inner, type_name)?,
+                ABIHandle::Std(abi.into()),
+            ),
+            ABIRef::Ship(abi) => (
+                struct_fields(&abi.inner, type_name)?,
+                ABIHandle::Ship(abi.into()),
+            ),
+        };
+        Ok(PyStructIter {
+            decoder: slf.unbind(),
+            abi,
+            fields: fields.into_iter(),
+        })
+    }
+}
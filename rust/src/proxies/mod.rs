@@ -1,6 +1,8 @@
 pub mod abi;
+pub mod abi_store;
 pub mod asset;
 pub mod checksums;
+pub mod decoder;
 pub mod name;
 pub mod private_key;
 pub mod public_key;
@@ -13,6 +13,8 @@ pub struct PyName {
     pub inner: Name,
 }
 
+/// Hashes the raw `u64` value, same as `__hash__`, so a `Name` hashes the same
+/// whether it's used as a key from Rust or Python.
 impl Hash for PyName {
     fn hash<H: Hasher>(&self, state: &mut H) {
         state.write_u64(self.inner.value())
@@ -39,11 +41,27 @@ impl From<Name> for PyName {
     }
 }
 
+impl PyName {
+    /// Ensure `inner` stringifies and parses back to the same value.
+    fn validated(inner: Name) -> PyResult<Self> {
+        let s = inner.as_str().map_err(|e| {
+            PyValueError::new_err(format!("Invalid name value {}: {e}", inner.value()))
+        })?;
+        match Name::from_str(&s) {
+            Ok(parsed) if parsed == inner => Ok(inner.into()),
+            _ => Err(PyValueError::new_err(format!(
+                "Invalid name value {}, doesn't round trip through {s:?}",
+                inner.value()
+            ))),
+        }
+    }
+}
+
 #[pymethods]
 impl PyName {
     #[staticmethod]
     pub fn from_int(value: u64) -> PyResult<Self> {
-        Ok(Name::from(value).into())
+        PyName::validated(Name::from(value))
     }
 
     #[staticmethod]
@@ -53,7 +71,7 @@ impl PyName {
         decoder
             .unpack(&mut inner)
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
-        Ok(inner.into())
+        PyName::validated(inner)
     }
 
     #[staticmethod]
@@ -64,6 +82,38 @@ impl PyName {
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    /// Opt-in validator for user input, unlike `from_str` errors point to the
+    /// exact offending character.
+    #[staticmethod]
+    pub fn validate(s: &str) -> PyResult<()> {
+        let len = s.chars().count();
+        if len > 13 {
+            return Err(PyValueError::new_err(format!(
+                "Name {s:?} is {len} chars long, max is 13"
+            )));
+        }
+        for (i, c) in s.chars().enumerate() {
+            // 13th char only has 4 bits available
+            let valid = match i {
+                12 => matches!(c, '.' | '1'..='5' | 'a'..='j'),
+                _ => matches!(c, '.' | '1'..='5' | 'a'..='z'),
+            };
+            if !valid {
+                let allowed = if i == 12 { "a-j, 1-5 or ." } else { "a-z, 1-5 or ." };
+                return Err(PyValueError::new_err(format!(
+                    "Invalid character {c:?} at index {i} of name {s:?}, expected {allowed}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Non raising check, true when `from_str` would succeed.
+    #[staticmethod]
+    pub fn is_valid(s: &str) -> bool {
+        Name::from_str(s).is_ok()
+    }
+
     #[staticmethod]
     pub fn try_from(value: NameLike) -> PyResult<PyName> {
         match value {
@@ -84,12 +134,111 @@ impl PyName {
         self.inner.value()
     }
 
-    fn __str__(&self) -> PyResult<String> {
+    /// Parse a fixed width name string, trailing `.` padding is ignored.
+    #[staticmethod]
+    pub fn from_padded_string(s: &str) -> PyResult<Self> {
+        if s.len() > 13 {
+            return Err(PyValueError::new_err(format!(
+                "Padded name string can't be longer than 13 chars: {s:?}"
+            )));
+        }
+        PyName::from_str_py(s.trim_end_matches('.'))
+    }
+
+    /// Fixed width 13 char representation, right padded with `.`.
+    pub fn to_padded_string(&self) -> String {
+        format!("{:.<13}", self.__str__())
+    }
+
+    /// False if the name can't be converted to a string
+    pub fn startswith(&self, prefix: &str) -> bool {
         self.inner
             .as_str()
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+            .is_ok_and(|s| s.starts_with(prefix))
     }
 
+    /// False if the name can't be converted to a string
+    pub fn endswith(&self, suffix: &str) -> bool {
+        self.inner
+            .as_str()
+            .is_ok_and(|s| s.ends_with(suffix))
+    }
+
+    /// Part after the last dot, `eosio.token` -> `token`, names without a dot
+    /// are returned unchanged.
+    pub fn suffix(&self) -> PyResult<PyName> {
+        let value = self.inner.value();
+        let mut remaining_bits_after_last_dot = 0u32;
+        let mut tmp = 0u32;
+        // walk the first 12 chars left to right, 5 bits each
+        for remaining_bits in (4..=59u32).rev().step_by(5) {
+            if (value >> remaining_bits) & 0x1f == 0 {
+                tmp = remaining_bits;
+            } else {
+                remaining_bits_after_last_dot = tmp;
+            }
+        }
+
+        // 13th char only has 4 bits
+        let thirteenth_char = value & 0x0f;
+        if thirteenth_char != 0 {
+            remaining_bits_after_last_dot = tmp;
+        }
+
+        if remaining_bits_after_last_dot == 0 {
+            return Ok(self.clone());
+        }
+
+        let mask = (1u64 << remaining_bits_after_last_dot) - 16;
+        let shift = 64 - remaining_bits_after_last_dot;
+        PyName::from_int(((value & mask) << shift) + (thirteenth_char << (shift - 1)))
+    }
+
+    /// Part before the last dot, `eosio.token` -> `eosio`, names without a dot
+    /// are returned unchanged.
+    pub fn prefix(&self) -> PyResult<PyName> {
+        let value = self.inner.value();
+        let mut result = value;
+        let mut non_dot_seen = false;
+        let mut mask = 0x0fu64;
+        let mut offset = 0u32;
+        // walk chars right to left, 13th char first
+        while offset <= 59 {
+            if (value >> offset) & mask == 0 {
+                if non_dot_seen {
+                    result = (value >> offset) << offset;
+                    break;
+                }
+            } else {
+                non_dot_seen = true;
+            }
+
+            if offset == 0 {
+                offset += 4;
+                mask = 0x1f;
+            } else {
+                offset += 5;
+            }
+        }
+        PyName::from_int(result)
+    }
+
+    /// JSON friendly representation, meant as target for json encoder
+    /// `default` hooks: `default=lambda o: o.to_json_value()`
+    pub fn to_json_value(&self) -> String {
+        self.__str__()
+    }
+
+    /// Infallible for names built through the constructors as they validate,
+    /// raw decoded values that can't be stringified render as
+    /// `<invalid name 0x...>`, which can't be mistaken for a valid name.
+    fn __str__(&self) -> String {
+        self.inner
+            .as_str()
+            .unwrap_or_else(|_| format!("<invalid name {:#018x}>", self.inner.value()))
+    }
+
+    /// Stable across processes, equal to the name's `u64` value.
     fn __hash__(&self) -> u64 {
         self.inner.value()
     }
@@ -98,14 +247,10 @@ impl PyName {
         self.inner.value()
     }
 
-    fn __richcmp__(&self, other: &PyName, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    /// Ordering follows the encoded `u64` value, same as on chain tables and
+    /// sorted containers, which is not lexicographic string order.
+    fn __richcmp__(&self, other: &PyName, op: CompareOp) -> bool {
+        op.matches(self.inner.value().cmp(&other.inner.value()))
     }
 }
 
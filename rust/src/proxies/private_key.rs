@@ -1,10 +1,12 @@
 use crate::proxies::public_key::PyPublicKey;
+use crate::proxies::signature::PySignature;
 use antelope::chain::key_type::KeyType;
 use antelope::chain::private_key::PrivateKey;
 use antelope::serializer::{Encoder, Packer};
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use sha2::{Digest, Sha256};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 
@@ -33,6 +35,26 @@ impl From<PrivateKey> for PyPrivateKey {
     }
 }
 
+/// Parse a curve key type string, only curves with plain private keys apply.
+fn key_type_from_str(s: &str) -> PyResult<KeyType> {
+    match s.to_uppercase().as_str() {
+        "K1" => Ok(KeyType::K1),
+        "R1" => Ok(KeyType::R1),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid key type {s:?}, expected K1 or R1"
+        ))),
+    }
+}
+
+/// Curve name as used in key & signature string prefixes.
+pub(crate) fn key_type_name(key_type: KeyType) -> &'static str {
+    match key_type {
+        KeyType::K1 => "K1",
+        KeyType::R1 => "R1",
+        KeyType::WA => "WA",
+    }
+}
+
 #[pymethods]
 impl PyPrivateKey {
     #[staticmethod]
@@ -61,6 +83,27 @@ impl PyPrivateKey {
         }
     }
 
+    /// Deterministic key derived from the sha256 of `seed`, defaults to K1.
+    ///
+    /// Meant for tests & reproducible setups only, this is NOT a KDF and
+    /// offers no protection for low entropy seeds like passphrases.
+    #[staticmethod]
+    #[pyo3(signature = (seed, key_type=None))]
+    pub fn from_seed(seed: &[u8], key_type: Option<&str>) -> PyResult<Self> {
+        let key_type = key_type.map(key_type_from_str).transpose()?.unwrap_or(KeyType::K1);
+        let digest: [u8; 32] = Sha256::digest(seed).into();
+        Ok(PrivateKey::from((digest.to_vec(), key_type)).into())
+    }
+
+    /// Fresh random key of curve `key_type`, `K1` or `R1`.
+    #[staticmethod]
+    pub fn generate(key_type: &str) -> PyResult<Self> {
+        let inner = PrivateKey::random(key_type_from_str(key_type)?)
+            .map_err(|e| PyValueError::new_err(format!("Error generating key: {e}")))?;
+
+        Ok(PyPrivateKey { inner })
+    }
+
     #[staticmethod]
     pub fn random(key_type: u8) -> PyResult<Self> {
         let key_type = KeyType::try_from(key_type)
@@ -85,6 +128,20 @@ impl PyPrivateKey {
         })
     }
 
+    /// Matching public key on the same curve, same as `get_public()`.
+    #[getter]
+    pub fn public_key(&self) -> PyResult<PyPublicKey> {
+        self.get_public()
+    }
+
+    /// Sign arbitrary bytes, hashed with sha256 internally like the chain does.
+    pub fn sign(&self, data: &[u8]) -> PyResult<PySignature> {
+        self.inner
+            .sign_message(&data.to_vec())
+            .map(|sig| sig.into())
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    }
+
     pub fn sign_message(&self, msg: Vec<u8>) -> PyResult<Vec<u8>> {
         let mut encoder = Encoder::new(0);
         let sig = self
@@ -95,11 +152,36 @@ impl PyPrivateKey {
         Ok(encoder.get_bytes().to_vec())
     }
 
+    /// Curve name, `K1`, `R1` or `WA`
+    #[getter(r#type)]
+    pub fn key_type(&self) -> &'static str {
+        key_type_name(self.inner.key_type)
+    }
+
     #[getter]
     pub fn raw(&self) -> &[u8] {
         &self.inner.value
     }
 
+    /// Legacy WIF encoding, only available for K1 keys.
+    pub fn to_legacy_string(&self) -> PyResult<String> {
+        if self.inner.key_type != KeyType::K1 {
+            return Err(PyValueError::new_err(format!(
+                "Legacy encoding is only defined for K1 keys, not {}",
+                key_type_name(self.inner.key_type)
+            )));
+        }
+        let mut data = vec![0x80u8];
+        data.extend_from_slice(&self.inner.value);
+        let checksum = Sha256::digest(Sha256::digest(&data));
+        data.extend_from_slice(&checksum[..4]);
+        Ok(bs58::encode(data).into_string())
+    }
+
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -111,13 +193,9 @@ impl PyPrivateKey {
         h.finish()
     }
 
-    fn __richcmp__(&self, other: &PyPrivateKey, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    /// Ordered by serialized form, curve type byte first then key data.
+    fn __richcmp__(&self, other: &PyPrivateKey, op: CompareOp) -> bool {
+        let this = (self.inner.key_type.to_index(), &self.inner.value);
+        op.matches(this.cmp(&(other.inner.key_type.to_index(), &other.inner.value)))
     }
 }
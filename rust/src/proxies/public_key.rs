@@ -1,9 +1,12 @@
-use crate::proxies::private_key::PyPrivateKey;
+use crate::proxies::private_key::{key_type_name, PyPrivateKey};
+use crate::proxies::signature::{PySignature, SigLike};
+use antelope::chain::key_type::KeyType;
 use antelope::chain::public_key::PublicKey;
 use antelope::serializer::{Decoder, Encoder, Packer};
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use ripemd::{Digest, Ripemd160};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 
@@ -70,6 +73,12 @@ impl PyPublicKey {
         }
     }
 
+    /// Curve name, `K1`, `R1` or `WA`
+    #[getter(r#type)]
+    pub fn key_type(&self) -> &'static str {
+        key_type_name(self.inner.key_type)
+    }
+
     #[getter]
     pub fn raw(&self) -> &[u8] {
         &self.inner.value
@@ -81,6 +90,30 @@ impl PyPublicKey {
         encoder.get_bytes().to_vec()
     }
 
+    /// Check `sig` is a signature over `data` by this key.
+    pub fn verify(&self, data: &[u8], sig: SigLike) -> PyResult<bool> {
+        let sig = PySignature::try_from(sig)?;
+        Ok(sig.inner.verify_message(&data.to_vec(), &self.inner))
+    }
+
+    /// Legacy `EOS` prefixed encoding, only available for K1 keys.
+    pub fn to_legacy_string(&self) -> PyResult<String> {
+        if self.inner.key_type != KeyType::K1 {
+            return Err(PyValueError::new_err(format!(
+                "Legacy encoding is only defined for K1 keys, not {}",
+                key_type_name(self.inner.key_type)
+            )));
+        }
+        let mut data = self.inner.value.clone();
+        let checksum = Ripemd160::digest(&data);
+        data.extend_from_slice(&checksum[..4]);
+        Ok(format!("EOS{}", bs58::encode(data).into_string()))
+    }
+
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -92,13 +125,9 @@ impl PyPublicKey {
         h.finish()
     }
 
-    fn __richcmp__(&self, other: &PyPublicKey, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    /// Ordered by serialized form, curve type byte first then key data.
+    fn __richcmp__(&self, other: &PyPublicKey, op: CompareOp) -> bool {
+        let this = (self.inner.key_type.to_index(), &self.inner.value);
+        op.matches(this.cmp(&(other.inner.key_type.to_index(), &other.inner.value)))
     }
 }
@@ -1,3 +1,6 @@
+use crate::proxies::private_key::key_type_name;
+use crate::proxies::public_key::PyPublicKey;
+use antelope::chain::key_type::KeyType;
 use antelope::chain::signature::Signature;
 use antelope::serializer::{Decoder, Encoder, Packer};
 use pyo3::basic::CompareOp;
@@ -59,12 +62,40 @@ impl PySignature {
         }
     }
 
+    /// Curve name, `K1`, `R1` or `WA`
+    #[getter(r#type)]
+    pub fn key_type(&self) -> &'static str {
+        key_type_name(self.inner.key_type)
+    }
+
+    /// Recover the public key that signed `data`, only K1 & R1 signatures are
+    /// recoverable.
+    pub fn recover(&self, data: &[u8]) -> PyResult<PyPublicKey> {
+        if !matches!(self.inner.key_type, KeyType::K1 | KeyType::R1) {
+            return Err(PyValueError::new_err(format!(
+                "Can't recover key from {:?} signature",
+                self.inner.key_type
+            )));
+        }
+        // recovery id byte followed by r & s
+        if self.inner.value.len() != 65 || !(27..=34).contains(&self.inner.value[0]) {
+            return Err(PyValueError::new_err(
+                "Malformed signature, can't recover key",
+            ));
+        }
+        Ok(self.inner.recover_message(&data.to_vec()).into())
+    }
+
     pub fn encode(&self) -> Vec<u8> {
         let mut encoder = Encoder::new(0);
         self.inner.pack(&mut encoder);
         encoder.get_bytes().to_vec()
     }
 
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
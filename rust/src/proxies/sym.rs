@@ -1,5 +1,5 @@
-use crate::proxies::sym_code::PySymbolCode;
-use antelope::chain::asset::Symbol;
+use crate::proxies::sym_code::{PySymbolCode, SymCodeLike};
+use antelope::chain::asset::{Symbol, ASSET_MAX_PRECISION};
 use antelope::serializer::{Decoder, Encoder, Packer};
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::PyValueError;
@@ -18,6 +18,7 @@ pub enum SymLike {
     Raw([u8; 8]),
     Str(String),
     Int(u64),
+    Parts((String, u8)),
     Cls(PySymbol),
 }
 
@@ -37,6 +38,12 @@ impl From<Symbol> for PySymbol {
 impl PySymbol {
     #[staticmethod]
     pub fn from_bytes(buffer: &[u8]) -> PyResult<Self> {
+        if buffer.len() < 8 {
+            return Err(PyValueError::new_err(format!(
+                "Symbol needs at least 8 bytes, got {}",
+                buffer.len()
+            )));
+        }
         let mut decoder = Decoder::new(buffer);
         let mut inner: Symbol = Default::default();
         decoder
@@ -58,12 +65,30 @@ impl PySymbol {
         Ok(Symbol::from(sym).into())
     }
 
+    /// Attach a precision to an already parsed symbol code.
+    #[staticmethod]
+    pub fn from_code(code: SymCodeLike, precision: u8) -> PyResult<Self> {
+        if precision > ASSET_MAX_PRECISION {
+            return Err(PyValueError::new_err(format!(
+                "Precision {precision} exceeds max of {ASSET_MAX_PRECISION}"
+            )));
+        }
+        let code = PySymbolCode::try_from(code)?;
+        Ok(Symbol::from((code.inner.value() << 8) | precision as u64).into())
+    }
+
+    #[staticmethod]
+    pub fn from_parts(code: &str, precision: u8) -> PyResult<Self> {
+        PySymbol::from_code(SymCodeLike::Str(code.to_string()), precision)
+    }
+
     #[staticmethod]
     pub fn try_from(value: SymLike) -> PyResult<PySymbol> {
         match value {
             SymLike::Raw(raw) => PySymbol::from_bytes(&raw),
             SymLike::Str(s) => PySymbol::from_str_py(&s),
             SymLike::Int(sym) => PySymbol::from_int(sym),
+            SymLike::Parts((code, precision)) => PySymbol::from_parts(&code, precision),
             SymLike::Cls(sym) => Ok(sym),
         }
     }
@@ -80,6 +105,18 @@ impl PySymbol {
         self.inner.precision()
     }
 
+    /// Full `precision,CODE` representation.
+    #[getter]
+    pub fn name(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// `10 ** precision`, exact integer counterpart of `unit`.
+    #[getter]
+    pub fn precision_divisor(&self) -> u64 {
+        10u64.pow(self.precision() as u32)
+    }
+
     #[getter]
     fn unit(&self) -> f64 {
         1.0 / (10u64.pow(self.precision() as u32) as f64)
@@ -91,6 +128,10 @@ impl PySymbol {
         encoder.get_bytes().to_vec()
     }
 
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
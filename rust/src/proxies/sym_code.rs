@@ -82,22 +82,24 @@ impl PySymbolCode {
         encoder.get_bytes().to_vec()
     }
 
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
-    fn __int___(&self) -> u64 {
+    fn __int__(&self) -> u64 {
         self.inner.value()
     }
 
-    fn __richcmp__(&self, other: PyRef<PySymbolCode>, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    fn __hash__(&self) -> u64 {
+        self.inner.value()
+    }
+
+    fn __richcmp__(&self, other: PyRef<PySymbolCode>, op: CompareOp) -> bool {
+        op.matches(self.inner.value().cmp(&other.inner.value()))
     }
 }
 
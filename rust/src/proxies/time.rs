@@ -4,12 +4,29 @@ use antelope::chain::time::{
     TimePoint, TimePointSec, BlockTimestamp
 };
 use antelope::serializer::{Decoder, Encoder, Packer};
+use chrono::NaiveDateTime;
 use pyo3::basic::CompareOp;
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
 
 use std::str::FromStr;
 
+const TIME_POINT_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// Parse a UTC `%Y-%m-%dT%H:%M:%S` timestamp with an optional fractional
+/// second part into microseconds since the Unix epoch, digits past the
+/// microsecond are truncated.
+pub fn parse_time_point(s: &str) -> Result<TimePoint, String> {
+    let Ok(dt) = NaiveDateTime::parse_from_str(s, TIME_POINT_FORMAT) else {
+        return TimePoint::from_str(s).map_err(|e| e.to_string());
+    };
+    u64::try_from(dt.and_utc().timestamp_micros())
+        .map(TimePoint::from)
+        .map_err(|_| format!("time_point {s:?} is before the Unix epoch"))
+}
+
 #[pyclass(frozen, name = "TimePoint")]
 #[derive(Debug, Clone)]
 pub struct PyTimePoint {
@@ -24,6 +41,14 @@ pub enum TimePointLike {
     Cls(PyTimePoint),
 }
 
+/// Right operand of `TimePoint.__sub__`, a microsecond offset or another
+/// `TimePoint` to diff against.
+#[derive(FromPyObject)]
+pub enum TimePointSubRhs {
+    Micros(i64),
+    Cls(PyTimePoint),
+}
+
 impl From<PyTimePoint> for TimePoint {
     fn from(value: PyTimePoint) -> Self {
         value.inner
@@ -36,6 +61,21 @@ impl From<TimePoint> for PyTimePoint {
     }
 }
 
+impl PyTimePoint {
+    fn shifted(&self, micros: i64) -> PyResult<PyTimePoint> {
+        self.inner
+            .elapsed
+            .checked_add_signed(micros)
+            .map(|elapsed| TimePoint::from(elapsed).into())
+            .ok_or_else(|| {
+                PyOverflowError::new_err(format!(
+                    "TimePoint {} shifted by {micros} microseconds out of range",
+                    self.inner.elapsed
+                ))
+            })
+    }
+}
+
 #[pymethods]
 impl PyTimePoint {
     #[staticmethod]
@@ -56,9 +96,55 @@ impl PyTimePoint {
     #[staticmethod]
     #[pyo3(name = "from_str")]
     pub fn from_str_py(s: &str) -> PyResult<Self> {
-        TimePoint::from_str(s)
-            .map(|sum| sum.into())
-            .map_err(|err| PyValueError::new_err(err.to_string()))
+        parse_time_point(s)
+            .map(|tp| tp.into())
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Build from a `datetime.datetime`, naive datetimes are taken as UTC.
+    #[staticmethod]
+    pub fn from_datetime(dt: Bound<PyAny>) -> PyResult<Self> {
+        let py = dt.py();
+        let datetime = py.import("datetime")?;
+        let utc = datetime.getattr("timezone")?.getattr("utc")?;
+
+        let dt = if dt.getattr("tzinfo")?.is_none() {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("tzinfo", &utc)?;
+            dt.call_method("replace", (), Some(&kwargs))?
+        } else {
+            dt
+        };
+
+        let epoch = datetime
+            .getattr("datetime")?
+            .call_method1("fromtimestamp", (0, &utc))?;
+        let delta = dt.sub(epoch)?;
+
+        let days: i64 = delta.getattr("days")?.extract()?;
+        let seconds: i64 = delta.getattr("seconds")?.extract()?;
+        let micros: i64 = delta.getattr("microseconds")?.extract()?;
+
+        let elapsed = (days * 86_400 + seconds) * 1_000_000 + micros;
+        u64::try_from(elapsed)
+            .map(PyTimePoint::from_int)
+            .map_err(|_| PyValueError::new_err("TimePoint can't be before the Unix epoch"))
+    }
+
+    /// Timezone aware UTC `datetime.datetime`.
+    pub fn to_datetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let datetime = py.import("datetime")?;
+        let utc = datetime.getattr("timezone")?.getattr("utc")?;
+
+        let epoch = datetime
+            .getattr("datetime")?
+            .call_method1("fromtimestamp", (0, utc))?;
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("microseconds", self.inner.elapsed)?;
+        let delta = datetime.getattr("timedelta")?.call((), Some(&kwargs))?;
+
+        epoch.add(delta)
     }
 
     #[staticmethod]
@@ -77,10 +163,45 @@ impl PyTimePoint {
         encoder.get_bytes().to_vec()
     }
 
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
+    /// Shift by `micros` microseconds
+    fn __add__(&self, micros: i64) -> PyResult<PyTimePoint> {
+        self.shifted(micros)
+    }
+
+    /// Shift back by an `int` of microseconds, or the signed microsecond
+    /// difference when subtracting another `TimePoint`.
+    fn __sub__<'py>(
+        &self,
+        py: Python<'py>,
+        other: TimePointSubRhs,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match other {
+            TimePointSubRhs::Micros(micros) => {
+                let micros = micros.checked_neg().ok_or_else(|| {
+                    PyOverflowError::new_err(format!("Can't negate {micros} microseconds"))
+                })?;
+                self.shifted(micros)?.into_bound_py_any(py)
+            }
+            TimePointSubRhs::Cls(other) => {
+                let diff = self.inner.elapsed as i128 - other.inner.elapsed as i128;
+                let diff = i64::try_from(diff).map_err(|_| {
+                    PyOverflowError::new_err(format!(
+                        "TimePoint difference of {diff} microseconds out of range"
+                    ))
+                })?;
+                diff.into_bound_py_any(py)
+            }
+        }
+    }
+
     fn __richcmp__(&self, other: PyRef<PyTimePoint>, op: CompareOp) -> PyResult<bool> {
         match op {
             CompareOp::Eq => Ok(self.inner == other.inner),
@@ -98,6 +219,8 @@ impl Display for PyTimePoint {
     }
 }
 
+const TIME_POINT_SEC_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
 #[pyclass(frozen, name = "TimePointSec")]
 #[derive(Debug, Clone)]
 pub struct PyTimePointSec {
@@ -141,12 +264,20 @@ impl PyTimePointSec {
         TimePointSec::from(num).into()
     }
 
+    /// Parses `%Y-%m-%dT%H:%M:%S` UTC timestamps.
     #[staticmethod]
     #[pyo3(name = "from_str")]
     pub fn from_str_py(s: &str) -> PyResult<Self> {
-        TimePointSec::from_str(s)
-            .map(|sum| sum.into())
-            .map_err(|err| PyValueError::new_err(err.to_string()))
+        if let Ok(inner) = TimePointSec::from_str(s) {
+            return Ok(inner.into());
+        }
+        let dt = NaiveDateTime::parse_from_str(s, TIME_POINT_SEC_FORMAT).map_err(|err| {
+            PyValueError::new_err(format!("Invalid time_point_sec {s:?}: {err}"))
+        })?;
+        let seconds = u32::try_from(dt.and_utc().timestamp()).map_err(|_| {
+            PyValueError::new_err(format!("time_point_sec {s:?} out of range"))
+        })?;
+        Ok(PyTimePointSec::from_int(seconds))
     }
 
     #[staticmethod]
@@ -165,18 +296,25 @@ impl PyTimePointSec {
         encoder.get_bytes().to_vec()
     }
 
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
-    fn __richcmp__(&self, other: PyRef<PyTimePointSec>, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    /// Seconds since the Unix epoch
+    fn __int__(&self) -> u32 {
+        self.inner.seconds
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.inner.seconds as u64
+    }
+
+    fn __richcmp__(&self, other: PyRef<PyTimePointSec>, op: CompareOp) -> bool {
+        op.matches(self.inner.seconds.cmp(&other.inner.seconds))
     }
 }
 
@@ -186,6 +324,10 @@ impl Display for PyTimePointSec {
     }
 }
 
+const BLOCK_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const BLOCK_TIMESTAMP_EPOCH_MS: i64 = 946_684_800_000;
+const BLOCK_INTERVAL_MS: i64 = 500;
+
 #[pyclass(frozen, name = "BlockTimestamp")]
 #[derive(Debug, Clone)]
 pub struct PyBlockTimestamp {
@@ -229,12 +371,30 @@ impl PyBlockTimestamp {
         BlockTimestamp::from(num).into()
     }
 
+    #[staticmethod]
+    pub fn from_slot(slot: u32) -> Self {
+        BlockTimestamp::from(slot).into()
+    }
+
+    /// Parses `%Y-%m-%dT%H:%M:%S.%f` UTC timestamps, rounded down to their
+    /// half second slot.
     #[staticmethod]
     #[pyo3(name = "from_str")]
     pub fn from_str_py(s: &str) -> PyResult<Self> {
-        BlockTimestamp::from_str(s)
-            .map(|sum| sum.into())
-            .map_err(|err| PyValueError::new_err(err.to_string()))
+        if let Ok(inner) = BlockTimestamp::from_str(s) {
+            return Ok(inner.into());
+        }
+        let dt = NaiveDateTime::parse_from_str(s, BLOCK_TIMESTAMP_FORMAT).map_err(|err| {
+            PyValueError::new_err(format!("Invalid block_timestamp_type {s:?}: {err}"))
+        })?;
+        let since_epoch = dt.and_utc().timestamp_millis() - BLOCK_TIMESTAMP_EPOCH_MS;
+        let slot = u32::try_from(since_epoch / BLOCK_INTERVAL_MS)
+            .ok()
+            .filter(|_| since_epoch >= 0)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!("block_timestamp_type {s:?} out of range"))
+            })?;
+        Ok(PyBlockTimestamp::from_slot(slot))
     }
 
     #[staticmethod]
@@ -247,24 +407,36 @@ impl PyBlockTimestamp {
         }
     }
 
+    /// Half second intervals since 2000-01-01T00:00:00
+    #[getter]
+    pub fn slot(&self) -> u32 {
+        self.inner.slot
+    }
+
+    /// Truncates to the second, odd slots lose their half second.
+    pub fn to_time_point_sec(&self) -> PyResult<PyTimePointSec> {
+        let millis = self.inner.slot as i64 * BLOCK_INTERVAL_MS + BLOCK_TIMESTAMP_EPOCH_MS;
+        u32::try_from(millis / 1000)
+            .map(PyTimePointSec::from_int)
+            .map_err(|_| PyValueError::new_err("BlockTimestamp out of time_point_sec range"))
+    }
+
     pub fn encode(&self) -> Vec<u8> {
         let mut encoder = Encoder::new(0);
         self.inner.pack(&mut encoder);
         encoder.get_bytes().to_vec()
     }
 
+    pub fn to_json_value(&self) -> String {
+        self.inner.to_string()
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
 
-    fn __richcmp__(&self, other: PyRef<PyBlockTimestamp>, op: CompareOp) -> PyResult<bool> {
-        match op {
-            CompareOp::Eq => Ok(self.inner == other.inner),
-            CompareOp::Ne => Ok(self.inner != other.inner),
-            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
-                "Operation not implemented",
-            )),
-        }
+    fn __richcmp__(&self, other: PyRef<PyBlockTimestamp>, op: CompareOp) -> bool {
+        op.matches(self.inner.slot.cmp(&other.inner.slot))
     }
 }
 
@@ -14,7 +14,7 @@ use antelope::{
 use pyo3::{
     exceptions::{PyNotImplementedError, PyValueError},
     prelude::*,
-    types::{PyBytes, PyDict, PyList},
+    types::{PyBytes, PyDict, PyInt, PyList},
     IntoPyObjectExt,
 };
 use thiserror::Error;
@@ -30,19 +30,83 @@ use crate::proxies::{
     sym::PySymbol,
     sym_code::PySymbolCode,
 };
+use crate::serializer::path::TypePath;
 
-#[derive(Clone, Debug, Default)]
-struct DecodePath(Vec<String>);
+/// Default bound on struct/variant nesting while decoding, see
+/// [`DecodeOptions::max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = 128;
 
-impl DecodePath {
-    fn push<S: Into<String>>(&mut self, seg: S) {
-        self.0.push(seg.into())
+#[derive(Clone, Debug)]
+pub struct DecodeOptions {
+    /// Max amount of nested structs/variants, guards self-referential types
+    /// (e.g. `node { next: node? }`) against deep or adversarial input.
+    pub max_depth: usize,
+
+    /// Optional cumulative budget for the whole decoded tree, array lengths
+    /// count as elements and std values as the amount of input bytes they
+    /// consume, caps aggregate memory of untrusted input.
+    pub max_output: Option<usize>,
+
+    /// Return `PublicKey`, `Signature` & `Checksum*` proxies instead of their
+    /// string representation.
+    pub typed: bool,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        DecodeOptions {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_output: None,
+            typed: true,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DecodeCtx<'a> {
+    opts: &'a DecodeOptions,
+    path: TypePath,
+    depth: usize,
+    output: usize,
+}
+
+impl<'a> DecodeCtx<'a> {
+    fn new(opts: &'a DecodeOptions, type_name: &str) -> Self {
+        let mut path = TypePath::default();
+        path.push(type_name);
+        DecodeCtx {
+            opts,
+            path,
+            depth: 0,
+            output: 0,
+        }
+    }
+
+    fn enter(&mut self) -> Result<(), DecodeError> {
+        if self.depth >= self.opts.max_depth {
+            return Err(DecodeError::MaxDepth {
+                max: self.opts.max_depth,
+                path: self.path.as_str(),
+            });
+        }
+        self.depth += 1;
+        Ok(())
     }
-    fn pop(&mut self) {
-        self.0.pop();
+
+    fn leave(&mut self) {
+        self.depth -= 1;
     }
-    fn as_str(&self) -> String {
-        self.0.join(".")
+
+    /// Account `amount` towards the output budget, if there is one.
+    fn charge(&mut self, amount: usize) -> Result<(), DecodeError> {
+        self.output = self.output.saturating_add(amount);
+        match self.opts.max_output {
+            Some(max) if self.output > max => Err(DecodeError::OutputBudget {
+                max,
+                path: self.path.as_str(),
+            }),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -62,11 +126,20 @@ pub enum DecodeError {
         err: String,
     },
 
+    #[error("unexpected end of buffer while decoding `{what}` at `{path}`")]
+    UnexpectedEnd { what: String, path: String },
+
     #[error("unknown std type `{name}` at `{path}`")]
     UnknownStdType { name: String, path: String },
 
     #[error("unknown type `{name}` at `{path}`")]
     UnknownType { name: String, path: String },
+
+    #[error("max decode depth of {max} exceeded at `{path}`")]
+    MaxDepth { max: usize, path: String },
+
+    #[error("decode output budget of {max} exceeded at `{path}`")]
+    OutputBudget { max: usize, path: String },
 }
 
 impl From<DecodeError> for PyErr {
@@ -84,65 +157,212 @@ pub fn decode_abi_type<'py, ABI>(
 where
     ABI: ABIView + ABITypeResolver,
 {
-    let mut meta = abi
+    decode_abi_type_with_options(py, abi, type_name, decoder, &DecodeOptions::default())
+}
+
+pub fn decode_abi_type_with_options<'py, ABI>(
+    py: Python<'py>,
+    abi: &ABI,
+    type_name: &str,
+    decoder: &mut Decoder<'_>,
+    opts: &DecodeOptions,
+) -> PyResult<Bound<'py, PyAny>>
+where
+    ABI: ABIView + ABITypeResolver,
+{
+    let mut ctx = DecodeCtx::new(opts, type_name);
+    decode_type(py, abi, type_name, decoder, &mut ctx)
+}
+
+/// `uint8[]` shares its wire format with `bytes` (varuint32 length + raw
+/// bytes), true when no `modifiers` are left after the `Array` one and the
+/// element type is `uint8`.
+pub(crate) fn is_byte_array(meta: &ABIResolvedType, modifiers: &[TypeModifier]) -> bool {
+    modifiers.is_empty() && meta.is_std && meta.resolved_name == "uint8"
+}
+
+/// Least amount of bytes a std type takes on the wire, exact for fixed size
+/// types, variable ones need at least their length prefix / type byte.
+fn std_min_size(type_name: &str) -> usize {
+    match type_name {
+        "bool" | "uint8" | "int8" => 1,
+        "uint16" | "int16" => 2,
+        "uint32" | "int32" | "float32" | "time_point_sec" | "block_timestamp_type" => 4,
+        "uint64" | "int64" | "float64" | "time_point" | "name" | "symbol" | "symbol_code" => 8,
+        "uint128" | "int128" | "float128" | "asset" => 16,
+        "checksum160" => 20,
+        "extended_asset" => 24,
+        "int256" | "uint256" | "checksum256" => 32,
+        "public_key" => 34,
+        "checksum512" => 64,
+        "signature" => 66,
+        _ => 1,
+    }
+}
+
+/// Fail cleanly instead of reading past the end of untrusted input.
+fn ensure_remaining(
+    decoder: &Decoder<'_>,
+    need: usize,
+    what: &str,
+    path: &TypePath,
+) -> Result<(), DecodeError> {
+    if decoder.remaining() < need {
+        return Err(DecodeError::UnexpectedEnd {
+            what: what.into(),
+            path: path.as_str(),
+        });
+    }
+    Ok(())
+}
+
+fn read_u8(decoder: &mut Decoder<'_>, what: &str, path: &TypePath) -> Result<u8, DecodeError> {
+    ensure_remaining(decoder, 1, what, path)?;
+    let mut b = 0u8;
+    decoder.unpack(&mut b).map_err(|e| DecodeError::Unpack {
+        what: what.into(),
+        path: path.as_str(),
+        err: e.to_string(),
+    })?;
+    Ok(b)
+}
+
+/// LEB128 `varuint32` read a byte at a time, so a prefix cut short by the
+/// end of input is caught before the native unpacker sees it.
+fn read_varuint32(
+    decoder: &mut Decoder<'_>,
+    what: &str,
+    path: &TypePath,
+) -> Result<u32, DecodeError> {
+    let mut value = 0u64;
+    for shift in (0..35).step_by(7) {
+        let b = read_u8(decoder, what, path)?;
+        value |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return u32::try_from(value).map_err(|_| DecodeError::Unpack {
+                what: what.into(),
+                path: path.as_str(),
+                err: format!("varuint32 overflow {value}"),
+            });
+        }
+    }
+    Err(DecodeError::Unpack {
+        what: what.into(),
+        path: path.as_str(),
+        err: "varuint32 longer than 5 bytes".into(),
+    })
+}
+
+/// Payload of a `bytes`/`string`/`uint8[]`, the declared length is checked
+/// against the remaining input before anything is read.
+fn read_len_prefixed(
+    decoder: &mut Decoder<'_>,
+    what: &str,
+    path: &TypePath,
+) -> Result<Vec<u8>, DecodeError> {
+    let len = read_varuint32(decoder, what, path)? as usize;
+    ensure_remaining(decoder, len, what, path)?;
+
+    // the native decoder has no raw slice reads, pull whole words then the
+    // tail bytes
+    let mut out = Vec::with_capacity(len);
+    for _ in 0..len / 8 {
+        let mut word = 0u64;
+        decoder.unpack(&mut word).map_err(|e| DecodeError::Unpack {
+            what: what.into(),
+            path: path.as_str(),
+            err: e.to_string(),
+        })?;
+        out.extend_from_slice(&word.to_le_bytes());
+    }
+    for _ in 0..len % 8 {
+        out.push(read_u8(decoder, what, path)?);
+    }
+    Ok(out)
+}
+
+fn read_string(
+    decoder: &mut Decoder<'_>,
+    what: &str,
+    path: &TypePath,
+) -> Result<String, DecodeError> {
+    String::from_utf8(read_len_prefixed(decoder, what, path)?).map_err(|e| {
+        DecodeError::Unpack {
+            what: what.into(),
+            path: path.as_str(),
+            err: e.to_string(),
+        }
+    })
+}
+
+/// Resolve `type_name` and decode it, keeping the path & depth of `ctx`.
+fn decode_type<'py, ABI>(
+    py: Python<'py>,
+    abi: &ABI,
+    type_name: &str,
+    decoder: &mut Decoder<'_>,
+    ctx: &mut DecodeCtx<'_>,
+) -> PyResult<Bound<'py, PyAny>>
+where
+    ABI: ABIView + ABITypeResolver,
+{
+    let meta = abi
         .resolve_type(type_name)
         .map_err(|e| DecodeError::Resolve {
-            path: type_name.to_string(),
+            path: ctx.path.as_str(),
             source: e,
         })?;
 
-    let mut path = DecodePath::default();
-    path.push(type_name);
-
-    decode_with_meta(py, abi, &mut meta, decoder, &mut path)
+    decode_with_meta(py, abi, &meta, &meta.modifiers, decoder, ctx)
 }
 
 fn decode_with_meta<'py, ABI>(
     py: Python<'py>,
     abi: &ABI,
-    meta: &mut ABIResolvedType,
+    meta: &ABIResolvedType,
+    modifiers: &[TypeModifier],
     decoder: &mut Decoder<'_>,
-    path: &mut DecodePath,
+    ctx: &mut DecodeCtx<'_>,
 ) -> PyResult<Bound<'py, PyAny>>
 where
     ABI: ABIView + ABITypeResolver,
 {
-    if !meta.modifiers.is_empty() {
-        let this_mod = meta.modifiers.remove(0);
+    // modifiers apply outermost first, `rest` is handed down to the inner value
+    if let Some((this_mod, rest)) = modifiers.split_first() {
         match this_mod {
             TypeModifier::Optional => {
+                ensure_remaining(decoder, 1, "optional-flag", &ctx.path)?;
                 let mut flag: u8 = 0;
                 decoder.unpack(&mut flag).map_err(|e| DecodeError::Unpack {
                     what: "optional-flag".into(),
-                    path: path.as_str(),
+                    path: ctx.path.as_str(),
                     err: e.to_string(),
                 })?;
 
                 if flag == 0 {
                     return Ok(py.None().into_bound(py));
                 }
-                path.push("some");
-                let res = decode_with_meta(py, abi, meta, decoder, path);
-                path.pop();
+                ctx.path.push("some");
+                let res = decode_with_meta(py, abi, meta, rest, decoder, ctx);
+                ctx.path.pop();
                 return res;
             }
             TypeModifier::Array => {
-                let mut len_vu: VarUint32 = VarUint32::default();
-                decoder
-                    .unpack(&mut len_vu)
-                    .map_err(|e| DecodeError::Unpack {
-                        what: "array-length".into(),
-                        path: path.as_str(),
-                        err: e.to_string(),
-                    })?;
-                let len = len_vu.value() as usize;
+                if is_byte_array(meta, rest) {
+                    let raw = read_len_prefixed(decoder, "uint8[]", &ctx.path)?;
+                    ctx.charge(raw.len())?;
+                    return PyBytes::new(py, &raw).into_bound_py_any(py);
+                }
+
+                let len = read_varuint32(decoder, "array-length", &ctx.path)? as usize;
+                ctx.charge(len)?;
 
                 let list = PyList::empty(py);
                 for i in 0..len {
-                    path.push(format!("[{i}]"));
-                    let item = decode_with_meta(py, abi, meta, decoder, path)?;
+                    ctx.path.push(format!("[{i}]"));
+                    let item = decode_with_meta(py, abi, meta, rest, decoder, ctx)?;
                     list.append(item)?;
-                    path.pop();
+                    ctx.path.pop();
                 }
                 return Ok(list.into_any());
             }
@@ -150,40 +370,37 @@ where
                 if decoder.remaining() == 0 {
                     return Ok(py.None().into_bound(py));
                 }
-                path.push("extension");
-                let res = decode_with_meta(py, abi, meta, decoder, path);
-                path.pop();
+                ctx.path.push("extension");
+                let res = decode_with_meta(py, abi, meta, rest, decoder, ctx);
+                ctx.path.pop();
                 return res;
             }
         }
     }
 
     if meta.is_std {
-        return decode_std(py, meta, decoder, path);
+        let start = decoder.get_pos();
+        let val = decode_std(py, meta, decoder, &ctx.path, ctx.opts.typed)?;
+        ctx.charge(decoder.get_pos() - start)?;
+        return Ok(val);
     }
 
     if let Some(var_meta) = &meta.is_variant {
-        let mut idx_vu: VarUint32 = VarUint32::default();
-        decoder
-            .unpack(&mut idx_vu)
-            .map_err(|e| DecodeError::Unpack {
-                what: "variant-index".into(),
-                path: path.as_str(),
-                err: e.to_string(),
-            })?;
-        let idx = idx_vu.value() as usize;
+        let idx = read_varuint32(decoder, "variant-index", &ctx.path)? as usize;
 
         let inner_type_name = var_meta
             .types
             .get(idx)
             .ok_or_else(|| DecodeError::UnknownType {
                 name: format!("variant-idx {idx}"),
-                path: path.as_str(),
+                path: ctx.path.as_str(),
             })?;
 
-        path.push(format!("variant({idx})"));
-        let payload = decode_abi_type(py, abi, inner_type_name, decoder)?;
-        path.pop();
+        ctx.enter()?;
+        ctx.path.push(format!("variant({idx})"));
+        let payload = decode_type(py, abi, inner_type_name, decoder, ctx)?;
+        ctx.path.pop();
+        ctx.leave();
 
         if let Ok(dict) = payload.downcast::<PyDict>() {
             dict.set_item("type", inner_type_name)?;
@@ -193,34 +410,238 @@ where
     }
 
     if let Some(struct_def) = &meta.is_struct {
+        ctx.enter()?;
         let dict = if !struct_def.base.is_empty() {
-            let val = decode_abi_type(py, abi, &struct_def.base, decoder)?;
+            let val = decode_type(py, abi, &struct_def.base, decoder, ctx)?;
             val.downcast()?.to_owned()
         } else {
             PyDict::new(py)
         };
         for field in &struct_def.fields {
-            path.push(field.name.clone());
-            let val = decode_abi_type(py, abi, &field.r#type, decoder)?;
+            ctx.path.push(field.name.clone());
+            let val = decode_type(py, abi, &field.r#type, decoder, ctx)?;
             dict.set_item(&field.name, val)?;
-            path.pop();
+            ctx.path.pop();
         }
+        ctx.leave();
         return dict.into_bound_py_any(py);
     }
 
     Err(DecodeError::UnknownType {
         name: meta.resolved_name.clone(),
-        path: path.as_str(),
+        path: ctx.path.as_str(),
     }
     .into())
 }
 
+/// Advance `decoder` past a value of `type_name` without materializing any
+/// python objects.
+pub fn skip_abi_type<ABI>(
+    abi: &ABI,
+    type_name: &str,
+    decoder: &mut Decoder<'_>,
+) -> PyResult<()>
+where
+    ABI: ABIView + ABITypeResolver,
+{
+    let opts = DecodeOptions::default();
+    let mut ctx = DecodeCtx::new(&opts, type_name);
+    skip_type(abi, type_name, decoder, &mut ctx)
+}
+
+fn skip_type<ABI>(
+    abi: &ABI,
+    type_name: &str,
+    decoder: &mut Decoder<'_>,
+    ctx: &mut DecodeCtx<'_>,
+) -> PyResult<()>
+where
+    ABI: ABIView + ABITypeResolver,
+{
+    let meta = abi
+        .resolve_type(type_name)
+        .map_err(|e| DecodeError::Resolve {
+            path: ctx.path.as_str(),
+            source: e,
+        })?;
+
+    skip_with_meta(abi, &meta, &meta.modifiers, decoder, ctx)
+}
+
+fn skip_with_meta<ABI>(
+    abi: &ABI,
+    meta: &ABIResolvedType,
+    modifiers: &[TypeModifier],
+    decoder: &mut Decoder<'_>,
+    ctx: &mut DecodeCtx<'_>,
+) -> PyResult<()>
+where
+    ABI: ABIView + ABITypeResolver,
+{
+    // modifiers apply outermost first, `rest` is handed down to the inner value
+    if let Some((this_mod, rest)) = modifiers.split_first() {
+        match this_mod {
+            TypeModifier::Optional => {
+                ensure_remaining(decoder, 1, "optional-flag", &ctx.path)?;
+                let mut flag: u8 = 0;
+                decoder.unpack(&mut flag).map_err(|e| DecodeError::Unpack {
+                    what: "optional-flag".into(),
+                    path: ctx.path.as_str(),
+                    err: e.to_string(),
+                })?;
+
+                if flag == 0 {
+                    return Ok(());
+                }
+                ctx.path.push("some");
+                let res = skip_with_meta(abi, meta, rest, decoder, ctx);
+                ctx.path.pop();
+                return res;
+            }
+            TypeModifier::Array => {
+                if is_byte_array(meta, rest) {
+                    read_len_prefixed(decoder, "uint8[]", &ctx.path)?;
+                    return Ok(());
+                }
+
+                let len = read_varuint32(decoder, "array-length", &ctx.path)? as usize;
+
+                for i in 0..len {
+                    ctx.path.push(format!("[{i}]"));
+                    skip_with_meta(abi, meta, rest, decoder, ctx)?;
+                    ctx.path.pop();
+                }
+                return Ok(());
+            }
+            TypeModifier::Extension => {
+                if decoder.remaining() == 0 {
+                    return Ok(());
+                }
+                ctx.path.push("extension");
+                let res = skip_with_meta(abi, meta, rest, decoder, ctx);
+                ctx.path.pop();
+                return res;
+            }
+        }
+    }
+
+    if meta.is_std {
+        return skip_std(meta, decoder, &ctx.path);
+    }
+
+    if let Some(var_meta) = &meta.is_variant {
+        let idx = read_varuint32(decoder, "variant-index", &ctx.path)? as usize;
+
+        let inner_type_name = var_meta
+            .types
+            .get(idx)
+            .ok_or_else(|| DecodeError::UnknownType {
+                name: format!("variant-idx {idx}"),
+                path: ctx.path.as_str(),
+            })?;
+
+        ctx.enter()?;
+        ctx.path.push(format!("variant({idx})"));
+        skip_type(abi, inner_type_name, decoder, ctx)?;
+        ctx.path.pop();
+        ctx.leave();
+        return Ok(());
+    }
+
+    if let Some(struct_def) = &meta.is_struct {
+        ctx.enter()?;
+        if !struct_def.base.is_empty() {
+            skip_type(abi, &struct_def.base, decoder, ctx)?;
+        }
+        for field in &struct_def.fields {
+            ctx.path.push(field.name.clone());
+            skip_type(abi, &field.r#type, decoder, ctx)?;
+            ctx.path.pop();
+        }
+        ctx.leave();
+        return Ok(());
+    }
+
+    Err(DecodeError::UnknownType {
+        name: meta.resolved_name.clone(),
+        path: ctx.path.as_str(),
+    }
+    .into())
+}
+
+fn skip_std(
+    meta: &ABIResolvedType,
+    decoder: &mut Decoder<'_>,
+    path: &TypePath,
+) -> PyResult<()> {
+    ensure_remaining(decoder, std_min_size(&meta.resolved_name), &meta.resolved_name, path)?;
+
+    macro_rules! skip {
+        ($t:ty) => {{
+            let mut tmp: $t = Default::default();
+            decoder.unpack(&mut tmp).map_err(|e| DecodeError::Unpack {
+                what: meta.resolved_name.clone(),
+                path: path.as_str(),
+                err: e.to_string(),
+            })?;
+            Ok(())
+        }};
+    }
+
+    match meta.resolved_name.as_str() {
+        "bool" => skip!(bool),
+        "uint8" => skip!(u8),
+        "uint16" => skip!(u16),
+        "uint32" => skip!(u32),
+        "uint64" => skip!(u64),
+        "uint128" => skip!(u128),
+        "int8" => skip!(i8),
+        "int16" => skip!(i16),
+        "int32" => skip!(i32),
+        "int64" => skip!(i64),
+        "int128" => skip!(i128),
+        // same wire format as a checksum256, 32 raw bytes
+        "int256" | "uint256" => skip!(Checksum256),
+        "varuint32" => skip!(VarUint32),
+        "varint32" => Err(PyNotImplementedError::new_err(
+            "varint32 decoding not implemented",
+        )),
+        "float32" => skip!(f32),
+        "float64" => skip!(f64),
+        "float128" => skip!(Float128),
+        "time_point" => skip!(TimePoint),
+        "time_point_sec" => skip!(TimePointSec),
+        "block_timestamp_type" => skip!(BlockTimestamp),
+        "name" => skip!(Name),
+        "bytes" | "string" => read_len_prefixed(decoder, &meta.resolved_name, path)
+            .map(|_| ())
+            .map_err(|e| e.into()),
+        "checksum160" => skip!(Checksum160),
+        "checksum256" => skip!(Checksum256),
+        "checksum512" => skip!(Checksum512),
+        "public_key" => skip!(PublicKey),
+        "signature" => skip!(Signature),
+        "symbol" => skip!(Symbol),
+        "symbol_code" => skip!(SymbolCode),
+        "asset" => skip!(Asset),
+        "extended_asset" => skip!(ExtendedAsset),
+        _ => Err(DecodeError::UnknownStdType {
+            name: meta.resolved_name.clone(),
+            path: path.as_str(),
+        }
+        .into()),
+    }
+}
+
 fn decode_std<'py>(
     py: Python<'py>,
     meta: &ABIResolvedType,
     decoder: &mut Decoder<'_>,
-    path: &DecodePath,
+    path: &TypePath,
+    typed: bool,
 ) -> PyResult<Bound<'py, PyAny>> {
+    ensure_remaining(decoder, std_min_size(&meta.resolved_name), &meta.resolved_name, path)?;
+
     macro_rules! unpack_prim {
         ($t:ty) => {{
             let mut tmp: $t = Default::default();
@@ -245,6 +666,21 @@ fn decode_std<'py>(
         "int32" => unpack_prim!(i32),
         "int64" => unpack_prim!(i64),
         "int128" => unpack_prim!(i128),
+        "int256" | "uint256" => {
+            let mut raw: Checksum256 = Default::default();
+            decoder.unpack(&mut raw).map_err(|e| DecodeError::Unpack {
+                what: meta.resolved_name.clone(),
+                path: path.as_str(),
+                err: e.to_string(),
+            })?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("signed", meta.resolved_name == "int256")?;
+            py.get_type::<PyInt>().call_method(
+                "from_bytes",
+                (PyBytes::new(py, &raw.data), "little"),
+                Some(&kwargs),
+            )
+        }
         "varuint32" => {
             let mut vu: VarUint32 = VarUint32::default();
             decoder.unpack(&mut vu).map_err(|e| DecodeError::Unpack {
@@ -305,23 +741,10 @@ fn decode_std<'py>(
             PyName { inner: n }.into_bound_py_any(py)
         }
         "bytes" => {
-            let mut v: Vec<u8> = Vec::new();
-            decoder.unpack(&mut v).map_err(|e| DecodeError::Unpack {
-                what: meta.resolved_name.clone(),
-                path: path.as_str(),
-                err: e.to_string(),
-            })?;
+            let v = read_len_prefixed(decoder, &meta.resolved_name, path)?;
             PyBytes::new(py, v.as_slice()).into_bound_py_any(py)
         }
-        "string" => {
-            let mut s: String = String::new();
-            decoder.unpack(&mut s).map_err(|e| DecodeError::Unpack {
-                what: meta.resolved_name.clone(),
-                path: path.as_str(),
-                err: e.to_string(),
-            })?;
-            s.into_bound_py_any(py)
-        }
+        "string" => read_string(decoder, &meta.resolved_name, path)?.into_bound_py_any(py),
         "checksum160" => {
             let mut sum: Checksum160 = Default::default();
             decoder.unpack(&mut sum).map_err(|e| DecodeError::Unpack {
@@ -329,7 +752,11 @@ fn decode_std<'py>(
                 path: path.as_str(),
                 err: e.to_string(),
             })?;
-            PyChecksum160 { inner: sum }.into_bound_py_any(py)
+            if typed {
+                PyChecksum160 { inner: sum }.into_bound_py_any(py)
+            } else {
+                sum.to_string().into_bound_py_any(py)
+            }
         }
         "checksum256" => {
             let mut sum: Checksum256 = Default::default();
@@ -338,7 +765,11 @@ fn decode_std<'py>(
                 path: path.as_str(),
                 err: e.to_string(),
             })?;
-            PyChecksum256 { inner: sum }.into_bound_py_any(py)
+            if typed {
+                PyChecksum256 { inner: sum }.into_bound_py_any(py)
+            } else {
+                sum.to_string().into_bound_py_any(py)
+            }
         }
         "checksum512" => {
             let mut sum: Checksum512 = Default::default();
@@ -347,7 +778,11 @@ fn decode_std<'py>(
                 path: path.as_str(),
                 err: e.to_string(),
             })?;
-            PyChecksum512 { inner: sum }.into_bound_py_any(py)
+            if typed {
+                PyChecksum512 { inner: sum }.into_bound_py_any(py)
+            } else {
+                sum.to_string().into_bound_py_any(py)
+            }
         }
         "public_key" => {
             let mut pk: PublicKey = Default::default();
@@ -356,7 +791,11 @@ fn decode_std<'py>(
                 path: path.as_str(),
                 err: e.to_string(),
             })?;
-            PyPublicKey { inner: pk }.into_bound_py_any(py)
+            if typed {
+                PyPublicKey { inner: pk }.into_bound_py_any(py)
+            } else {
+                pk.to_string().into_bound_py_any(py)
+            }
         }
         "signature" => {
             let mut sig: Signature = Default::default();
@@ -365,7 +804,11 @@ fn decode_std<'py>(
                 path: path.as_str(),
                 err: e.to_string(),
             })?;
-            PySignature { inner: sig }.into_bound_py_any(py)
+            if typed {
+                PySignature { inner: sig }.into_bound_py_any(py)
+            } else {
+                sig.to_string().into_bound_py_any(py)
+            }
         }
         "symbol" => {
             let mut sym: Symbol = Default::default();
@@ -18,7 +18,10 @@ use antelope::{
 };
 use pyo3::{
     exceptions::{PyNotImplementedError, PyTypeError, PyValueError},
-    types::{PyAnyMethods, PyDict, PyList, PyListMethods},
+    types::{
+        IntoPyDict, PyAnyMethods, PyBytes, PyBytesMethods, PyDict, PyInt, PyList, PyListMethods,
+        PyTypeMethods,
+    },
     Bound, PyAny, PyErr, PyResult,
 };
 use thiserror::Error;
@@ -29,26 +32,15 @@ use crate::proxies::{
         PyChecksum160, PyChecksum256, PyChecksum512,
     },
     name::PyName,
+    private_key::PyPrivateKey,
     public_key::PyPublicKey,
     signature::PySignature,
     sym::PySymbol,
     sym_code::PySymbolCode,
+    time::{parse_time_point, PyBlockTimestamp, PyTimePoint, PyTimePointSec},
 };
-
-#[derive(Clone, Debug, Default)]
-struct EncodePath(Vec<String>);
-
-impl EncodePath {
-    fn push<S: Into<String>>(&mut self, s: S) {
-        self.0.push(s.into());
-    }
-    fn pop(&mut self) {
-        self.0.pop();
-    }
-    fn as_str(&self) -> String {
-        self.0.join(".")
-    }
-}
+use crate::serializer::decode::is_byte_array;
+use crate::serializer::path::TypePath;
 
 #[derive(Debug, Error)]
 pub enum EncodeError {
@@ -77,6 +69,13 @@ pub enum EncodeError {
     #[error("python type mismatch at `{path}`; expected {expected}")]
     TypeMismatch { path: String, expected: String },
 
+    #[error("field `{path}` expects {expected} but got {got}")]
+    ProxyMismatch {
+        path: String,
+        expected: String,
+        got: String,
+    },
+
     #[error("unknown std type `{name}` at `{path}`")]
     UnknownStdType { name: String, path: String },
 
@@ -113,32 +112,46 @@ pub fn encode_abi_type<'py, ABI>(
 where
     ABI: ABIView + ABITypeResolver,
 {
-    let mut meta = abi
+    let mut path = TypePath::default();
+    path.push(type_name);
+
+    encode_type(abi, type_name, value, encoder, &mut path)
+}
+
+/// Resolve `type_name` and encode `value` as it, keeping `path` for errors.
+fn encode_type<'py, ABI>(
+    abi: &ABI,
+    type_name: &str,
+    value: &Bound<'py, PyAny>,
+    encoder: &mut Encoder,
+    path: &mut TypePath,
+) -> PyResult<usize>
+where
+    ABI: ABIView + ABITypeResolver,
+{
+    let meta = abi
         .resolve_type(type_name)
         .map_err(|e| EncodeError::Resolve {
-            path: type_name.to_string(),
+            path: path.as_str(),
             source: e,
         })?;
 
-    let mut path = EncodePath::default();
-    path.push(type_name);
-
-    encode_with_meta(abi, &mut meta, value, encoder, &mut path)
+    encode_with_meta(abi, &meta, &meta.modifiers, value, encoder, path)
 }
 
 fn encode_with_meta<'py, ABI>(
     abi: &ABI,
-    meta: &mut ABIResolvedType,
+    meta: &ABIResolvedType,
+    modifiers: &[TypeModifier],
     value: &Bound<'py, PyAny>,
     encoder: &mut Encoder,
-    path: &mut EncodePath,
+    path: &mut TypePath,
 ) -> PyResult<usize>
 where
     ABI: ABIView + ABITypeResolver,
 {
-    if !meta.modifiers.is_empty() {
-        let this_mod = meta.modifiers.remove(0);
-
+    // modifiers apply outermost first, `rest` is handed down to the inner value
+    if let Some((this_mod, rest)) = modifiers.split_first() {
         match this_mod {
             TypeModifier::Optional => {
                 if value.is_none() {
@@ -146,12 +159,19 @@ where
                 }
                 let mut size = (1u8).pack(encoder);
                 path.push("some");
-                size += encode_with_meta(abi, meta, value, encoder, path)?;
+                size += encode_with_meta(abi, meta, rest, value, encoder, path)?;
                 path.pop();
                 return Ok(size);
             }
 
             TypeModifier::Array => {
+                // `uint8[]` shares its wire format with `bytes`
+                if is_byte_array(meta, rest) {
+                    if let Ok(raw) = value.downcast::<PyBytes>() {
+                        return Ok(raw.as_bytes().to_vec().pack(encoder));
+                    }
+                }
+
                 let seq = value
                     .downcast::<PyList>()
                     .map_err(|_| EncodeError::TypeMismatch {
@@ -164,7 +184,7 @@ where
 
                 for (i, item) in seq.iter().enumerate() {
                     path.push(format!("[{i}]"));
-                    size += encode_with_meta(abi, meta, &item, encoder, path)?;
+                    size += encode_with_meta(abi, meta, rest, &item, encoder, path)?;
                     path.pop();
                 }
                 return Ok(size);
@@ -175,7 +195,7 @@ where
                     return Ok(0);
                 }
                 path.push("extension");
-                let sz = encode_with_meta(abi, meta, value, encoder, path)?;
+                let sz = encode_with_meta(abi, meta, rest, value, encoder, path)?;
                 path.pop();
                 return Ok(sz);
             }
@@ -187,12 +207,12 @@ where
     }
 
     if let Some(var_meta) = &meta.is_variant {
-        let (idx, sel_ty) = detect_variant(path, abi, var_meta, value)?;
+        let (idx, sel_ty, payload) = detect_variant(path, abi, var_meta, value)?;
 
         let mut size = VarUint32::new(idx as u32).pack(encoder);
 
         path.push(format!("variant({idx})"));
-        size += encode_abi_type(abi, &sel_ty, value, encoder)?;
+        size += encode_type(abi, &sel_ty, &payload, encoder, path)?;
         path.pop();
 
         return Ok(size);
@@ -210,7 +230,7 @@ where
 
         /* base first */
         if !struct_def.base.is_empty() {
-            size += encode_abi_type(abi, &struct_def.base, value, encoder)?;
+            size += encode_type(abi, &struct_def.base, value, encoder, path)?;
         }
 
         /* fields */
@@ -232,7 +252,7 @@ where
             };
 
             path.push(field.name.clone());
-            size += encode_abi_type(abi, ty, &field_val, encoder)?;
+            size += encode_type(abi, ty, &field_val, encoder, path)?;
             path.pop();
         }
         return Ok(size);
@@ -245,12 +265,86 @@ where
     .into())
 }
 
+/// Widen an `f64` into little endian IEEE 754 binary128, exact for every
+/// finite value, infinities & NaN payloads carry over.
+fn f64_to_f128_bytes(f: f64) -> [u8; 16] {
+    const MANT_MASK: u64 = (1 << 52) - 1;
+
+    let bits = f.to_bits();
+    let sign = (bits >> 63) as u128;
+    let exp = ((bits >> 52) & 0x7ff) as i64;
+    let mut mant = bits & MANT_MASK;
+
+    let qexp = match exp {
+        0 if mant == 0 => 0,
+        0 => {
+            // subnormal in f64 but normal in f128, move the leading one into
+            // the implicit bit position
+            let shift = mant.leading_zeros() as i64 - 11;
+            mant = (mant << shift) & MANT_MASK;
+            -1022 - shift + 16383
+        }
+        0x7ff => 0x7fff,
+        _ => exp - 1023 + 16383,
+    } as u128;
+
+    ((sign << 127) | (qexp << 112) | ((mant as u128) << 60)).to_le_bytes()
+}
+
+/// Std type a proxy instance stands for, `None` if `value` is not a proxy.
+fn proxy_std_type(value: &Bound<'_, PyAny>) -> Option<&'static str> {
+    if value.is_instance_of::<PyName>() {
+        Some("name")
+    } else if value.is_instance_of::<PyAsset>() {
+        Some("asset")
+    } else if value.is_instance_of::<PyExtendedAsset>() {
+        Some("extended_asset")
+    } else if value.is_instance_of::<PySymbol>() {
+        Some("symbol")
+    } else if value.is_instance_of::<PySymbolCode>() {
+        Some("symbol_code")
+    } else if value.is_instance_of::<PyChecksum160>() {
+        Some("checksum160")
+    } else if value.is_instance_of::<PyChecksum256>() {
+        Some("checksum256")
+    } else if value.is_instance_of::<PyChecksum512>() {
+        Some("checksum512")
+    } else if value.is_instance_of::<PyPublicKey>() {
+        Some("public_key")
+    } else if value.is_instance_of::<PySignature>() {
+        Some("signature")
+    } else if value.is_instance_of::<PyTimePoint>() {
+        Some("time_point")
+    } else if value.is_instance_of::<PyTimePointSec>() {
+        Some("time_point_sec")
+    } else if value.is_instance_of::<PyBlockTimestamp>() {
+        Some("block_timestamp_type")
+    } else if value.is_instance_of::<PyPrivateKey>() {
+        // never valid as a field value
+        Some("private_key")
+    } else {
+        None
+    }
+}
+
 fn encode_std<'py>(
     meta: &ABIResolvedType,
     value: &Bound<'py, PyAny>,
     encoder: &mut Encoder,
-    path: &EncodePath,
+    path: &TypePath,
 ) -> PyResult<usize> {
+    // proxies only encode as the std type they represent
+    if let Some(proxy_type) = proxy_std_type(value) {
+        if proxy_type != meta.resolved_name {
+            return Err(EncodeError::ProxyMismatch {
+                path: path.as_str(),
+                expected: meta.resolved_name.clone(),
+                got: value.get_type().name()?.to_string(),
+            }
+            .into());
+        }
+    }
+
     macro_rules! extract {
         ($t:ty) => {
             value
@@ -270,6 +364,24 @@ fn encode_std<'py>(
         }};
     }
 
+    // wide ints also accept decimal strings, as JSON sources often carry them
+    macro_rules! decimal {
+        ($t:ty) => {{
+            let v: $t = if let Ok(v) = value.extract::<$t>() {
+                v
+            } else {
+                let s: String = extract!(String)?;
+                s.trim().parse::<$t>().map_err(|e| EncodeError::Parse {
+                    type_name: meta.resolved_name.clone(),
+                    value: s,
+                    path: path.as_str(),
+                    err: e.to_string(),
+                })?
+            };
+            Ok(v.pack(encoder))
+        }};
+    }
+
     match meta.resolved_name.as_str() {
         "bool" => {
             let flag = value.is_truthy()?;
@@ -278,13 +390,34 @@ fn encode_std<'py>(
         "uint8" => simple!(u8),
         "uint16" => simple!(u16),
         "uint32" => simple!(u32),
-        "uint64" => simple!(u64),
-        "uint128" => simple!(u128),
+        "uint64" => decimal!(u64),
+        "uint128" => decimal!(u128),
         "int8" => simple!(i8),
         "int16" => simple!(i16),
         "int32" => simple!(i32),
-        "int64" => simple!(i64),
-        "int128" => simple!(i128),
+        "int64" => decimal!(i64),
+        "int128" => decimal!(i128),
+        "int256" | "uint256" => {
+            if !value.is_instance_of::<PyInt>() {
+                return Err(EncodeError::TypeMismatch {
+                    path: path.as_str(),
+                    expected: "int".into(),
+                }
+                .into());
+            }
+            let kwargs = [("signed", meta.resolved_name == "int256")].into_py_dict(value.py())?;
+            let raw = value
+                .call_method("to_bytes", (32, "little"), Some(&kwargs))
+                .map_err(|e| EncodeError::Parse {
+                    type_name: meta.resolved_name.clone(),
+                    value: value.to_string(),
+                    path: path.as_str(),
+                    err: e.to_string(),
+                })?;
+            let raw: [u8; 32] = raw.downcast::<PyBytes>()?.as_bytes().try_into()?;
+            // same wire format as a checksum256, 32 raw bytes
+            Ok(Checksum256::from(raw).pack(encoder))
+        }
         "varuint32" => {
             let v: u32 = extract!(u32)?;
             Ok(VarUint32::new(v).pack(encoder))
@@ -293,18 +426,37 @@ fn encode_std<'py>(
         "float32" => simple!(f32),
         "float64" => simple!(f64),
         "float128" => {
-            let v: [u8; 16] = extract!([u8; 16])?;
+            let v = if let Ok(raw) = value.extract::<[u8; 16]>() {
+                Ok(raw)
+            } else if let Ok(f) = value.extract::<f64>() {
+                Ok(f64_to_f128_bytes(f))
+            } else if let Ok(s) = extract!(String) {
+                s.trim()
+                    .parse::<f64>()
+                    .map(f64_to_f128_bytes)
+                    .map_err(|e| EncodeError::Parse {
+                        type_name: meta.resolved_name.clone(),
+                        value: s,
+                        path: path.as_str(),
+                        err: e.to_string(),
+                    })
+            } else {
+                Err(EncodeError::TypeMismatch {
+                    path: path.as_str(),
+                    expected: "bytes[16]/float/string".into(),
+                })
+            }?;
             Ok(Float128::new(v).pack(encoder))
         }
         "time_point" => {
             let tp = if let Ok(elapsed) = extract!(u64) {
                 Ok(TimePoint { elapsed })
             } else if let Ok(s) = extract!(String) {
-                TimePoint::from_str(&s).map_err(|e| EncodeError::Parse {
+                parse_time_point(&s).map_err(|err| EncodeError::Parse {
                     type_name: meta.resolved_name.clone(),
                     value: s,
                     path: path.as_str(),
-                    err: e.to_string(),
+                    err,
                 })
             } else {
                 Err(EncodeError::TypeMismatch {
@@ -623,34 +775,82 @@ fn encode_std<'py>(
     }
 }
 
+/// True if `type_name` resolves to a plain struct declaring `field`, either
+/// directly or through its bases.
+fn struct_has_field(
+    path: &TypePath,
+    abi: &impl ABITypeResolver,
+    type_name: &str,
+    field: &str,
+) -> PyResult<bool> {
+    let mut next = type_name.to_string();
+    while !next.is_empty() {
+        let meta = abi.resolve_type(&next).map_err(|e| EncodeError::Resolve {
+            path: path.as_str(),
+            source: e,
+        })?;
+        let Some(struct_def) = meta.is_struct.filter(|_| meta.modifiers.is_empty()) else {
+            return Ok(false);
+        };
+        if struct_def.fields.iter().any(|f| f.name == field) {
+            return Ok(true);
+        }
+        next = struct_def.base;
+    }
+    Ok(false)
+}
+
+/// Pick the variant alternative for `value`, returns its index, type name and
+/// the payload to encode as that type.
 fn detect_variant<'py>(
-    path: &EncodePath,
+    path: &TypePath,
     abi: &impl ABITypeResolver,
     var_meta: &AbiVariant,
     value: &Bound<'py, PyAny>,
-) -> PyResult<(usize, String)> {
-    // struct variant
+) -> PyResult<(usize, String, Bound<'py, PyAny>)> {
+    let position = |tag: &str| {
+        var_meta
+            .types
+            .iter()
+            .position(|t| t == tag)
+            .ok_or_else(|| EncodeError::UnknownType {
+                name: format!("{} (alternative `{tag}`)", var_meta.name),
+                path: path.as_str(),
+            })
+    };
+
+    // tagged pair, `[type, value]`
+    if let Ok(pair) = value.downcast::<PyList>() {
+        if pair.len() == 2 {
+            if let Ok(tag) = pair.get_item(0)?.extract::<String>() {
+                if var_meta.types.contains(&tag) {
+                    let idx = position(&tag)?;
+                    return Ok((idx, tag, pair.get_item(1)?));
+                }
+            }
+        }
+    }
+
+    // dict variant, either
+    //   {type:'checksum256', value:'abcd…'}
+    //   {type:'packed_transaction', ...fields }
     if let Ok(d) = value.downcast::<PyDict>() {
         let tag_py = d.get_item("type")?;
         let tag: String = tag_py.extract().map_err(|_| EncodeError::TypeMismatch {
             path: format!("{}['type']", path.as_str()),
             expected: "string".into(),
         })?;
+        let idx = position(&tag)?;
 
-        let idx = var_meta
-            .types
-            .iter()
-            .position(|t| **t == tag)
-            .ok_or_else(|| EncodeError::UnknownType {
-                name: var_meta.name.clone(),
-                path: path.as_str(),
-            })?;
-
-        // everything except "type" is considered the payload; allow both
-        //   {type:'packed_transaction', ...fields }
-        //   {type:'checksum256', value:'abcd…'}
+        // a struct alternative with its own `value` field is in flat form
+        if d.len()? == 2 && !struct_has_field(path, abi, &tag, "value")? {
+            if let Ok(payload) = d.get_item("value") {
+                return Ok((idx, tag, payload));
+            }
+        }
 
-        return Ok((idx, var_meta.types[idx].clone()));
+        // everything except "type" is the struct payload
+        return Ok((idx, tag, value.clone()));
     }
 
     // std type variant
@@ -674,7 +874,7 @@ fn detect_variant<'py>(
     }
 
     if let Some((idx, ty)) = candidate {
-        return Ok((idx, ty));
+        return Ok((idx, ty, value.clone()));
     }
 
     Err(PyTypeError::new_err(format!(
@@ -0,0 +1,24 @@
+/// Location of the value being encoded/decoded, used to point errors at the
+/// offending field.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TypePath(Vec<String>);
+
+impl TypePath {
+    pub(crate) fn push<S: Into<String>>(&mut self, seg: S) {
+        self.0.push(seg.into())
+    }
+    pub(crate) fn pop(&mut self) {
+        self.0.pop();
+    }
+    /// Dotted path, array indexes attach to their field, e.g. `a.b[2].c`
+    pub(crate) fn as_str(&self) -> String {
+        let mut out = String::new();
+        for seg in &self.0 {
+            if !out.is_empty() && !seg.starts_with('[') {
+                out.push('.');
+            }
+            out.push_str(seg);
+        }
+        out
+    }
+}
@@ -1,19 +1,31 @@
 use antelope::chain::action::{Action, PermissionLevel};
 use antelope::chain::name::Name as NativeName;
+use antelope::chain::public_key::PublicKey;
 use antelope::chain::time::TimePointSec;
 use antelope::chain::transaction::TransactionHeader;
 use antelope::chain::transaction::{
     CompressionType, PackedTransaction, SignedTransaction, Transaction,
 };
 use antelope::chain::varint::VarUint32;
+use antelope::serializer::{Decoder, Encoder, Packer};
 use antelope::util::bytes_to_hex;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
 use pyo3::exceptions::PyValueError;
 use pyo3::types::PyDict;
 use pyo3::{pyfunction, FromPyObject, PyResult};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::str::FromStr;
 
+use crate::proxies::abi::PyABI;
 use crate::proxies::checksums::{PyChecksum256, Sum256Like};
 use crate::proxies::private_key::PyPrivateKey;
+use crate::proxies::public_key::{PubKeyLike, PyPublicKey};
+use crate::proxies::signature::{PySignature, SigLike};
+use crate::serializer::encode::encode_abi_type;
 use pyo3::prelude::*;
 
 #[derive(FromPyObject)]
@@ -32,38 +44,70 @@ impl From<&PyPermissionLevel> for PyResult<PermissionLevel> {
     }
 }
 
+/// Action payload, either already packed (`bytes`, `bytearray` or a sequence
+/// of ints) or a params mapping/object that will be packed using the action's
+/// type from the contract ABI.
 #[derive(FromPyObject)]
-pub struct PyAction {
+pub enum ActionDataLike<'py> {
+    Raw(Vec<u8>),
+    Params(Bound<'py, PyAny>),
+}
+
+#[derive(FromPyObject)]
+pub struct PyAction<'py> {
     account: String,
     name: String,
     authorization: Vec<PyPermissionLevel>,
-    data: Vec<u8>,
+    data: ActionDataLike<'py>,
 }
 
-impl From<&PyAction> for PyResult<Action> {
-    fn from(py_action: &PyAction) -> Self {
+impl PyAction<'_> {
+    fn to_native(&self, abis: Option<&HashMap<String, PyRef<'_, PyABI>>>) -> PyResult<Action> {
         let mut auths = Vec::new();
-        for auth in py_action.authorization.iter() {
+        for auth in self.authorization.iter() {
             let maybe_perm: PyResult<PermissionLevel> = auth.into();
             auths.push(maybe_perm?);
         }
+
+        let data = match &self.data {
+            ActionDataLike::Raw(raw) => raw.clone(),
+            ActionDataLike::Params(params) => {
+                let abi = abis.and_then(|abis| abis.get(&self.account)).ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "No ABI for {} provided, can't pack {} params",
+                        self.account, self.name
+                    ))
+                })?;
+
+                let action_type = abi.get_action_type(&self.name)?;
+
+                let mut encoder = Encoder::new(0);
+                encode_abi_type(&abi.inner, &action_type, params, &mut encoder)?;
+                encoder.get_bytes().to_vec()
+            }
+        };
+
         Ok(Action {
-            account: NativeName::from_str(&py_action.account)
+            account: NativeName::from_str(&self.account)
                 .map_err(|e| PyValueError::new_err(e.to_string()))?,
-            name: NativeName::from_str(&py_action.name)
+            name: NativeName::from_str(&self.name)
                 .map_err(|e| PyValueError::new_err(e.to_string()))?,
             authorization: auths,
-            data: py_action.data.clone(),
+            data,
         })
     }
 }
 
+/// Usage limits are optional and default to 0, letting the chain bill the
+/// actual usage.
 #[derive(FromPyObject)]
 pub struct PyTransactionHeader {
     pub expiration: u32,
     pub ref_block_num: u16,
     pub ref_block_prefix: u32,
+    #[pyo3(default)]
     pub max_net_usage_words: u32,
+    #[pyo3(default)]
     pub max_cpu_usage_ms: u8,
     pub delay_sec: u32,
 }
@@ -81,59 +125,449 @@ impl From<PyTransactionHeader> for TransactionHeader {
     }
 }
 
-#[pyfunction]
-pub fn sign_tx(
+/// Chain id, packed transaction & the sha256 of the packed context free
+/// data, 32 zero bytes when there is none.
+fn signing_data(transaction: &Transaction, chain_id: &[u8], packed_cfd: &[u8]) -> Vec<u8> {
+    if packed_cfd.is_empty() {
+        return transaction.signing_data(chain_id);
+    }
+    let mut encoder = Encoder::new(0);
+    transaction.pack(&mut encoder);
+
+    let mut data = chain_id.to_vec();
+    data.extend_from_slice(encoder.get_bytes());
+    data.extend_from_slice(&Sha256::digest(packed_cfd));
+    data
+}
+
+/// Parse the `compression` argument, true for zlib.
+fn is_zlib(compression: &str) -> PyResult<bool> {
+    match compression.to_lowercase().as_str() {
+        "none" => Ok(false),
+        "zlib" => Ok(true),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid compression {compression:?}, expected none or zlib"
+        ))),
+    }
+}
+
+fn zlib_compress(data: &[u8]) -> PyResult<Vec<u8>> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(data)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| PyValueError::new_err(format!("Error compressing trx: {e}")))
+}
+
+fn zlib_decompress(data: &[u8]) -> PyResult<Vec<u8>> {
+    let mut out = Vec::new();
+    ZlibDecoder::new(data)
+        .read_to_end(&mut out)
+        .map_err(|e| PyValueError::new_err(format!("Error decompressing trx: {e}")))?;
+    Ok(out)
+}
+
+/// Build, sign using every key in `sign_keys` & pack a transaction.
+#[allow(clippy::too_many_arguments)]
+fn sign_and_pack(
     chain_id: Sum256Like,
     header: PyTransactionHeader,
     actions: Vec<PyAction>,
-    sign_key: &PyPrivateKey,
+    sign_keys: &[&PyPrivateKey],
+    abis: Option<HashMap<String, PyRef<PyABI>>>,
+    context_free_actions: Vec<PyAction>,
+    context_free_data: Vec<Vec<u8>>,
+    compression: &str,
 ) -> PyResult<Py<PyDict>> {
+    let zlib = is_zlib(compression)?;
+
     // convert to valid checksum256
     let chain_id = PyChecksum256::try_from(chain_id)?;
 
     // convert py actions into native
-    let mut _actions: Vec<Action> = Vec::with_capacity(actions.len());
-    for action in actions.iter() {
-        let act: PyResult<Action> = action.into();
-        _actions.push(act?);
-    }
-    let actions: Vec<Action> = _actions;
+    let to_native = |actions: &[PyAction]| -> PyResult<Vec<Action>> {
+        actions.iter().map(|a| a.to_native(abis.as_ref())).collect()
+    };
+    let context_free_actions = to_native(&context_free_actions)?;
+    let actions = to_native(&actions)?;
 
     // put together transaction to sign
     let transaction = Transaction {
         header: header.into(),
-        context_free_actions: vec![],
+        context_free_actions,
         actions,
         extension: vec![],
     };
 
+    let packed_cfd = if context_free_data.is_empty() {
+        vec![]
+    } else {
+        let mut encoder = Encoder::new(0);
+        context_free_data.pack(&mut encoder);
+        encoder.get_bytes().to_vec()
+    };
+
     // sign using chain id
-    let sign_data = transaction.signing_data(chain_id.raw());
+    let sign_data = signing_data(&transaction, chain_id.raw(), &packed_cfd);
+    let mut signatures = Vec::with_capacity(sign_keys.len());
+    for key in sign_keys {
+        signatures.push(
+            key.inner
+                .sign_message(&sign_data)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?,
+        );
+    }
     let signed_tx = SignedTransaction {
         transaction,
-        signatures: vec![sign_key
-            .inner
-            .sign_message(&sign_data)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?],
-        context_free_data: vec![],
+        signatures,
+        context_free_data,
     };
 
-    // finally PackedTransaction is the payload to be broadcasted
+    // finally PackedTransaction is the payload to be broadcasted, signatures
+    // always cover the uncompressed transaction
     let tx = PackedTransaction::from_signed(signed_tx, CompressionType::NONE)
         .map_err(|e| PyValueError::new_err(format!("Error signing packed trx: {e}")))?;
 
+    let (packed_trx, packed_cfd) = if zlib {
+        let cfd = if packed_cfd.is_empty() {
+            packed_cfd
+        } else {
+            zlib_compress(&packed_cfd)?
+        };
+        (zlib_compress(&tx.packed_transaction)?, cfd)
+    } else {
+        (tx.packed_transaction, packed_cfd)
+    };
+
     // pack and return into a bounded PyDict
     Python::with_gil(|py| {
         let dict_tx = PyDict::new(py);
 
         let signatures: Vec<String> = tx.signatures.iter().map(|s| s.to_string()).collect();
-        let packed_trx: String = bytes_to_hex(&tx.packed_transaction);
+        let packed_trx: String = bytes_to_hex(&packed_trx);
 
         dict_tx.set_item("signatures", signatures)?;
-        dict_tx.set_item("compression", false)?;
-        dict_tx.set_item("packed_context_free_data", "".to_string())?;
+        dict_tx.set_item("compression", zlib)?;
+        dict_tx.set_item("packed_context_free_data", bytes_to_hex(&packed_cfd))?;
         dict_tx.set_item("packed_trx", packed_trx)?;
 
         Ok(dict_tx.unbind())
     })
 }
+
+/// Build, sign & pack a transaction.
+///
+/// Each action's `data` can either be the already packed action bytes, or a
+/// params object (dict/struct) which gets packed using the action type found
+/// in `abis[account]`, a mapping of contract account to its `ABI`.
+///
+/// `context_free_actions` take the same shape as `actions`, their
+/// `context_free_data` is committed to by the signatures.
+///
+/// `compression` is either `none` or `zlib`, the latter compresses the
+/// returned `packed_trx` & `packed_context_free_data`.
+#[pyfunction]
+#[pyo3(signature = (
+    chain_id, header, actions, sign_key, abis = None,
+    context_free_actions = vec![], context_free_data = vec![], compression = "none"
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn sign_tx(
+    chain_id: Sum256Like,
+    header: PyTransactionHeader,
+    actions: Vec<PyAction>,
+    sign_key: &PyPrivateKey,
+    abis: Option<HashMap<String, PyRef<PyABI>>>,
+    context_free_actions: Vec<PyAction>,
+    context_free_data: Vec<Vec<u8>>,
+    compression: &str,
+) -> PyResult<Py<PyDict>> {
+    sign_and_pack(
+        chain_id,
+        header,
+        actions,
+        &[sign_key],
+        abis,
+        context_free_actions,
+        context_free_data,
+        compression,
+    )
+}
+
+/// Same as `sign_tx` but signs with every key in `keys`, for transactions
+/// requiring multiple authorizations.
+#[pyfunction]
+#[pyo3(signature = (
+    header, actions, chain_id, keys, abis = None,
+    context_free_actions = vec![], context_free_data = vec![], compression = "none"
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn build_transaction(
+    header: PyTransactionHeader,
+    actions: Vec<PyAction>,
+    chain_id: Sum256Like,
+    keys: Vec<PyRef<PyPrivateKey>>,
+    abis: Option<HashMap<String, PyRef<PyABI>>>,
+    context_free_actions: Vec<PyAction>,
+    context_free_data: Vec<Vec<u8>>,
+    compression: &str,
+) -> PyResult<Py<PyDict>> {
+    let keys: Vec<&PyPrivateKey> = keys.iter().map(|k| &**k).collect();
+    sign_and_pack(
+        chain_id,
+        header,
+        actions,
+        &keys,
+        abis,
+        context_free_actions,
+        context_free_data,
+        compression,
+    )
+}
+
+/// Packed transaction as raw bytes or hex string, like the `packed_trx` field
+/// returned by `sign_tx`.
+#[derive(FromPyObject)]
+pub enum PackedTrxLike {
+    Raw(Vec<u8>),
+    Hex(String),
+}
+
+impl PackedTrxLike {
+    fn into_bytes(self, what: &str) -> PyResult<Vec<u8>> {
+        match self {
+            PackedTrxLike::Raw(raw) => Ok(raw),
+            PackedTrxLike::Hex(s) => hex::decode(&s)
+                .map_err(|e| PyValueError::new_err(format!("Invalid {what} hex: {e}"))),
+        }
+    }
+}
+
+/// Decode `packed_trx` & return it along the uncompressed packed context free
+/// data, `compression` applies to both like in `sign_tx` output.
+fn unpack_transaction(
+    packed_trx: PackedTrxLike,
+    packed_cfd: Option<PackedTrxLike>,
+    compression: &str,
+) -> PyResult<(Transaction, Vec<u8>)> {
+    let zlib = is_zlib(compression)?;
+    let inflate = |raw: Vec<u8>| -> PyResult<Vec<u8>> {
+        if zlib && !raw.is_empty() {
+            zlib_decompress(&raw)
+        } else {
+            Ok(raw)
+        }
+    };
+
+    let raw = inflate(packed_trx.into_bytes("packed_trx")?)?;
+    let packed_cfd = match packed_cfd {
+        Some(cfd) => inflate(cfd.into_bytes("packed_context_free_data")?)?,
+        None => vec![],
+    };
+
+    let mut transaction = Transaction::default();
+    Decoder::new(&raw)
+        .unpack(&mut transaction)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok((transaction, packed_cfd))
+}
+
+fn recover_keys(
+    transaction: &Transaction,
+    chain_id: Sum256Like,
+    signatures: Vec<SigLike>,
+    packed_cfd: &[u8],
+) -> PyResult<Vec<PublicKey>> {
+    let chain_id = PyChecksum256::try_from(chain_id)?;
+    let sign_data = signing_data(transaction, chain_id.raw(), packed_cfd);
+
+    let mut keys = Vec::with_capacity(signatures.len());
+    for sig in signatures {
+        keys.push(PySignature::try_from(sig)?.recover(&sign_data)?.inner);
+    }
+    Ok(keys)
+}
+
+/// Recover the public key behind each of `signatures` for a packed
+/// transaction, in the same order.
+///
+/// `packed_context_free_data` must be passed when the transaction was signed
+/// with context free data, as the signatures commit to its digest.
+///
+/// `compression` is either `none` or `zlib`, matching the `compression` flag
+/// returned by `sign_tx`.
+#[pyfunction]
+#[pyo3(signature = (
+    chain_id, packed_trx, signatures, packed_context_free_data = None, compression = "none"
+))]
+pub fn recover_signers(
+    chain_id: Sum256Like,
+    packed_trx: PackedTrxLike,
+    signatures: Vec<SigLike>,
+    packed_context_free_data: Option<PackedTrxLike>,
+    compression: &str,
+) -> PyResult<Vec<PyPublicKey>> {
+    let (transaction, packed_cfd) =
+        unpack_transaction(packed_trx, packed_context_free_data, compression)?;
+    let keys = recover_keys(&transaction, chain_id, signatures, &packed_cfd)?;
+    Ok(keys.into_iter().map(|k| k.into()).collect())
+}
+
+/// Same nesting limit nodeos applies to delegated account permissions.
+const MAX_AUTH_DEPTH: usize = 6;
+
+/// `{actor, permission}` as found in chain `get_account` responses.
+#[derive(FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct PyAuthPermissionLevel {
+    actor: String,
+    permission: String,
+}
+
+impl PyAuthPermissionLevel {
+    fn key(&self) -> String {
+        format!("{}@{}", self.actor, self.permission)
+    }
+}
+
+#[derive(FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct PyKeyWeight {
+    key: PubKeyLike,
+    weight: u16,
+}
+
+#[derive(FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct PyPermissionLevelWeight {
+    permission: PyAuthPermissionLevel,
+    weight: u16,
+}
+
+/// Authority definition, same shape as the `required_auth` field of chain
+/// `get_account` responses, `waits` are accepted but never count towards the
+/// threshold as they can't be satisfied off chain.
+#[derive(FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct PyAuthority {
+    threshold: u32,
+    #[pyo3(default)]
+    keys: Vec<PyKeyWeight>,
+    #[pyo3(default)]
+    accounts: Vec<PyPermissionLevelWeight>,
+}
+
+struct Authority {
+    threshold: u32,
+    keys: Vec<(PublicKey, u16)>,
+    accounts: Vec<(String, u16)>,
+}
+
+impl TryFrom<PyAuthority> for Authority {
+    type Error = PyErr;
+
+    fn try_from(value: PyAuthority) -> PyResult<Self> {
+        let mut keys = Vec::with_capacity(value.keys.len());
+        for kw in value.keys {
+            keys.push((PyPublicKey::try_from(kw.key)?.inner, kw.weight));
+        }
+        Ok(Authority {
+            threshold: value.threshold,
+            keys,
+            accounts: value
+                .accounts
+                .iter()
+                .map(|aw| (aw.permission.key(), aw.weight))
+                .collect(),
+        })
+    }
+}
+
+/// Sum the weight of every key in `signers` & every satisfied delegated
+/// account permission, unknown permissions are never satisfied.
+fn authority_satisfied(
+    level: &str,
+    authorities: &HashMap<String, Authority>,
+    signers: &[PublicKey],
+    depth: usize,
+) -> bool {
+    if depth > MAX_AUTH_DEPTH {
+        return false;
+    }
+    let Some(auth) = authorities.get(level) else {
+        return false;
+    };
+
+    let mut weight: u32 = 0;
+    for (key, w) in auth.keys.iter() {
+        if signers.contains(key) {
+            weight += *w as u32;
+        }
+    }
+    for (account, w) in auth.accounts.iter() {
+        if weight >= auth.threshold {
+            break;
+        }
+        if authority_satisfied(account, authorities, signers, depth + 1) {
+            weight += *w as u32;
+        }
+    }
+    weight >= auth.threshold
+}
+
+/// Recover the transaction signers & check every permission level used in
+/// its actions against `authorities`, a mapping of `actor@permission` to an
+/// authority definition.
+///
+/// Returns a mapping of each used `actor@permission` to whether the recovered
+/// keys satisfy it, permissions missing from `authorities` are unsatisfied.
+#[pyfunction]
+#[pyo3(signature = (
+    packed_trx, chain_id, signatures, authorities,
+    packed_context_free_data = None, compression = "none"
+))]
+pub fn check_transaction_authorization(
+    packed_trx: PackedTrxLike,
+    chain_id: Sum256Like,
+    signatures: Vec<SigLike>,
+    authorities: HashMap<String, PyAuthority>,
+    packed_context_free_data: Option<PackedTrxLike>,
+    compression: &str,
+) -> PyResult<HashMap<String, bool>> {
+    let (transaction, packed_cfd) =
+        unpack_transaction(packed_trx, packed_context_free_data, compression)?;
+    let signers = recover_keys(&transaction, chain_id, signatures, &packed_cfd)?;
+
+    let authorities = authorities
+        .into_iter()
+        .map(|(level, auth)| Ok((level, Authority::try_from(auth)?)))
+        .collect::<PyResult<HashMap<_, _>>>()?;
+
+    let mut result = HashMap::new();
+    for action in transaction.actions.iter() {
+        for perm in action.authorization.iter() {
+            let level = format!("{}@{}", perm.actor, perm.permission);
+            if result.contains_key(&level) {
+                continue;
+            }
+            let satisfied = authority_satisfied(&level, &authorities, &signers, 0);
+            result.insert(level, satisfied);
+        }
+    }
+    Ok(result)
+}
+
+/// Verify each `(signature, message, public_key)` triple, the GIL is released
+/// while verifying, returns per item results.
+#[pyfunction]
+pub fn verify_batch(
+    py: Python<'_>,
+    items: Vec<(PySignature, Vec<u8>, PyPublicKey)>,
+) -> Vec<bool> {
+    py.allow_threads(|| {
+        items
+            .iter()
+            .map(|(sig, msg, key)| sig.inner.verify_message(msg, &key.inner))
+            .collect()
+    })
+}
@@ -52,6 +52,7 @@ from ._lowlevel import (
     Symbol as Symbol,
     Asset as Asset,
     ExtendedAsset as ExtendedAsset,
+    sum_assets as sum_assets,
 
     TimePoint as TimePoint,
     TimePointSec as TimePointSec,
@@ -59,10 +60,19 @@ from ._lowlevel import (
 
     ABI as ABI,
     ShipABI as ShipABI,
+    AbiStore as AbiStore,
+
+    Decoder as Decoder,
 
     builtin_types as builtin_types,
 
-    sign_tx as sign_tx
+    benchmark_decode as benchmark_decode,
+
+    sign_tx as sign_tx,
+    build_transaction as build_transaction,
+    verify_batch as verify_batch,
+    recover_signers as recover_signers,
+    check_transaction_authorization as check_transaction_authorization
 )
 
 builtin_classes: tuple[Type[Any], ...] = (
@@ -40,6 +40,13 @@ def enc_hook(obj: Any) -> Any:
             raise NotImplementedError(f"Objects of type {type(obj)} are not supported")
 
 
+def json_enc_hook(obj: Any) -> Any:
+    if hasattr(obj, 'to_json_value'):
+        return obj.to_json_value()
+    else:
+        raise NotImplementedError(f"Objects of type {type(obj)} are not supported")
+
+
 def dec_hook(type: Type, obj: Any) -> Any:
     if hasattr(type, 'try_from'):
         return type.try_from(obj)
@@ -1 +1,24 @@
+import json
+from typing import Sequence
+
 import pdbp
+
+from antelope_rs import ABI
+
+
+def make_abi(
+    structs: list[dict],
+    actions: Sequence[dict] = (),
+    variants: Sequence[dict] = ()
+) -> ABI:
+    return ABI.from_str(json.dumps({
+        'version': 'eosio::abi/1.1',
+        'types': [],
+        'structs': structs,
+        'actions': actions,
+        'tables': [],
+        'ricardian_clauses': [],
+        'error_messages': [],
+        'abi_extensions': [],
+        'variants': variants
+    }))
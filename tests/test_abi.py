@@ -0,0 +1,346 @@
+import hashlib
+import json
+
+import pytest
+
+from antelope_rs import ABI, Asset
+
+
+abi = ABI.from_str(json.dumps({
+    'version': 'eosio::abi/1.1',
+    'types': [
+        {'new_type_name': 'account_name', 'type': 'name'}
+    ],
+    'structs': [
+        {
+            'name': 'transfer',
+            'base': '',
+            'fields': [
+                {'name': 'from', 'type': 'account_name'},
+                {'name': 'to', 'type': 'account_name'},
+                {'name': 'quantity', 'type': 'asset'},
+                {'name': 'memo', 'type': 'string'}
+            ]
+        }
+    ],
+    'actions': [
+        {'name': 'transfer', 'type': 'transfer', 'ricardian_contract': ''}
+    ],
+    'tables': [],
+    'ricardian_clauses': [],
+    'error_messages': [],
+    'abi_extensions': [],
+    'variants': []
+}))
+
+
+def test_terminal_type():
+    assert abi.terminal_type('account_name') == ('name', False, False, False)
+    assert abi.terminal_type('account_name[]') == ('name', True, False, False)
+    assert abi.terminal_type('account_name?') == ('name', False, True, False)
+    assert abi.terminal_type('asset$') == ('asset', False, False, True)
+    assert abi.terminal_type('transfer') == ('transfer', False, False, False)
+
+
+def test_pack_rejects_mismatched_proxy():
+    with pytest.raises(ValueError, match='expects string but got Asset'):
+        abi.pack('transfer', {
+            'from': 'alice',
+            'to': 'bob',
+            'quantity': '1.0000 EOS',
+            'memo': Asset.try_from('1.0000 EOS')
+        })
+
+
+def test_existence_predicates():
+    assert abi.has_action('transfer')
+    assert not abi.has_action('issue')
+
+    assert not abi.has_table('accounts')
+
+    assert abi.has_struct('transfer')
+    assert not abi.has_struct('account_name')
+
+    assert abi.has_type('account_name')
+    assert not abi.has_type('transfer')
+
+
+shapes_abi = ABI.from_str(json.dumps({
+    'version': 'eosio::abi/1.1',
+    'types': [],
+    'structs': [
+        {
+            'name': 'point',
+            'base': '',
+            'fields': [
+                {'name': 'x', 'type': 'int32'},
+                {'name': 'y', 'type': 'int32'}
+            ]
+        },
+        {
+            'name': 'shape',
+            'base': '',
+            'fields': [
+                {'name': 'owner', 'type': 'name'},
+                {'name': 'label', 'type': 'string?'},
+                {'name': 'points', 'type': 'point[]'},
+                {'name': 'fill', 'type': 'fill_v'},
+                {'name': 'z_index', 'type': 'uint16$'}
+            ]
+        }
+    ],
+    'actions': [],
+    'tables': [],
+    'ricardian_clauses': [],
+    'error_messages': [],
+    'abi_extensions': [],
+    'variants': [
+        {'name': 'fill_v', 'types': ['uint32', 'point']}
+    ]
+}))
+
+
+@pytest.mark.parametrize(
+    'shape',
+    (
+        {
+            'owner': 'alice',
+            'label': None,
+            'points': [],
+            'fill': 0xff0000,
+            'z_index': None
+        },
+        {
+            'owner': 'bob',
+            'label': 'triangle',
+            'points': [{'x': 0, 'y': 0}, {'x': 1, 'y': -1}, {'x': -1, 'y': 1}],
+            'fill': {'type': 'point', 'x': 5, 'y': 5},
+            'z_index': 3
+        },
+    ),
+    ids=('minimal', 'full')
+)
+def test_pack_unpack_roundtrip(shape: dict):
+    raw = shapes_abi.pack('shape', shape)
+
+    assert shapes_abi.unpack('shape', raw) == shape
+    assert shapes_abi.pack('shape', shapes_abi.unpack('shape', raw)) == raw
+
+
+def test_pack_absent_extension():
+    shape = {
+        'owner': 'alice',
+        'label': None,
+        'points': [],
+        'fill': 1
+    }
+    raw = shapes_abi.pack('shape', shape)
+
+    assert shapes_abi.unpack('shape', raw) == {**shape, 'z_index': None}
+
+
+def test_compute_hash():
+    digest = abi.compute_hash()
+
+    assert digest == ABI.from_bytes(abi.encode()).compute_hash()
+    assert digest == ABI.from_str(str(abi)).compute_hash()
+    assert digest.raw == hashlib.sha256(abi.encode()).digest()
+
+    assert shapes_abi.compute_hash() != digest
+
+
+def test_diff():
+    abi_dict = json.loads(str(abi))
+
+    abi_dict['structs'][0]['fields'][3]['type'] = 'bytes'
+    abi_dict['structs'].append({
+        'name': 'issue',
+        'base': '',
+        'fields': [{'name': 'to', 'type': 'name'}]
+    })
+    abi_dict['actions'].append(
+        {'name': 'issue', 'type': 'issue', 'ricardian_contract': ''}
+    )
+    upgraded = ABI.from_str(json.dumps(abi_dict))
+
+    diff = abi.diff(upgraded)
+
+    assert diff['added_structs'] == ['issue']
+    assert diff['removed_structs'] == []
+    assert diff['changed_structs'] == {
+        'transfer': {
+            'added_fields': [],
+            'removed_fields': [],
+            'changed_fields': {'memo': ('string', 'bytes')},
+            'base': None
+        }
+    }
+    assert diff['added_actions'] == ['issue']
+    assert diff['removed_actions'] == []
+
+    reverse = upgraded.diff(abi)
+    assert reverse['removed_actions'] == ['issue']
+    assert reverse['changed_structs']['transfer']['changed_fields'] == {
+        'memo': ('bytes', 'string')
+    }
+
+    assert not any(abi.diff(abi).values())
+
+
+def test_get_action_and_table_type():
+    assert abi.get_action_type('transfer') == 'transfer'
+
+    with pytest.raises(KeyError):
+        abi.get_action_type('issue')
+
+    with pytest.raises(KeyError):
+        abi.get_table_type('accounts')
+
+
+def test_from_dict():
+    for src in (abi, shapes_abi):
+        assert ABI.from_dict(json.loads(str(src))) == src
+
+    with pytest.raises(ValueError, match='Invalid ABI dict, bad `structs`'):
+        ABI.from_dict({'version': 'eosio::abi/1.1', 'structs': 'nope'})
+
+
+def test_validate():
+    assert abi.validate() is None
+    assert shapes_abi.validate() is None
+
+    abi_dict = json.loads(str(abi))
+    abi_dict['structs'][0]['fields'].append({'name': 'fee', 'type': 'fee_info'})
+    abi_dict['structs'][0]['fields'].append({'name': 'tags', 'type': 'tag[]'})
+    broken = ABI.from_dict(abi_dict)
+
+    with pytest.raises(ValueError, match='fee_info, tag\\[\\]'):
+        broken.validate()
+
+
+def test_resolve_type_deep():
+    tree = shapes_abi.resolve_type_deep('shape')
+
+    assert tree['kind'] == 'struct'
+    assert tree['base'] is None
+
+    fields = {f['name']: f['type'] for f in tree['fields']}
+
+    points = fields['points']
+    assert points['kind'] == 'struct'
+    assert points['resolved_name'] == 'point'
+    assert points['modifiers'] == ['array']
+    assert [f['type']['resolved_name'] for f in points['fields']] == ['int32', 'int32']
+
+    assert fields['label']['kind'] == 'std'
+    assert fields['label']['modifiers'] == ['optional']
+
+    fill = fields['fill']
+    assert fill['kind'] == 'variant'
+    assert [t['resolved_name'] for t in fill['types']] == ['uint32', 'point']
+    assert fill['types'][1]['fields'][0]['name'] == 'x'
+
+    with pytest.raises(ValueError, match='max resolve depth of 1'):
+        shapes_abi.resolve_type_deep('shape', max_depth=1)
+
+
+def test_merge():
+    # shares an identical `point` struct with shapes_abi
+    geo_dict = json.loads(str(shapes_abi))
+    geo_dict['structs'] = [
+        geo_dict['structs'][0],
+        {
+            'name': 'region',
+            'base': '',
+            'fields': [{'name': 'corners', 'type': 'point[]'}]
+        }
+    ]
+    geo_dict['variants'] = []
+    geo = ABI.from_dict(geo_dict)
+
+    merged = shapes_abi.merge(geo)
+
+    assert [s.name for s in merged.structs] == ['point', 'shape', 'region']
+    assert merged.validate() is None
+    assert merged.unpack('region', geo.pack('region', {'corners': [{'x': 1, 'y': 2}]})) == {
+        'corners': [{'x': 1, 'y': 2}]
+    }
+
+    # same name, different body
+    geo_dict['structs'][0]['fields'][1]['type'] = 'int64'
+    with pytest.raises(ValueError, match='Conflicting definitions for struct `point`'):
+        shapes_abi.merge(ABI.from_dict(geo_dict))
+
+
+def test_name_lists():
+    assert abi.action_names() == [a['name'] for a in abi._actions]
+    assert abi.struct_names() == ['transfer']
+    assert abi.table_names() == []
+
+    assert shapes_abi.struct_names() == ['point', 'shape']
+    assert shapes_abi.variant_names() == ['fill_v']
+
+
+@pytest.mark.parametrize(
+    'forms',
+    (
+        (5, ['uint32', 5], {'type': 'uint32', 'value': 5}),
+        (
+            {'type': 'point', 'x': 1, 'y': 2},
+            ['point', {'x': 1, 'y': 2}],
+            {'type': 'point', 'value': {'x': 1, 'y': 2}},
+        ),
+    ),
+    ids=('std', 'struct')
+)
+def test_variant_forms(forms: tuple):
+    raws = [shapes_abi.pack('fill_v', form) for form in forms]
+
+    assert raws[0] == raws[1] == raws[2]
+
+
+def test_variant_struct_with_value_field():
+    wrapped_dict = json.loads(str(shapes_abi))
+    wrapped_dict['structs'].append({
+        'name': 'wrapper',
+        'base': '',
+        'fields': [{'name': 'value', 'type': 'uint32'}]
+    })
+    wrapped_dict['variants'] = [{'name': 'fill_v', 'types': ['uint32', 'wrapper']}]
+    wrapped = ABI.from_dict(wrapped_dict)
+
+    raw = b'\x01' + (5).to_bytes(4, 'little')
+
+    # flat form, `value` is the struct field not the payload
+    assert wrapped.pack('fill_v', {'type': 'wrapper', 'value': 5}) == raw
+    assert wrapped.pack('fill_v', ['wrapper', {'value': 5}]) == raw
+    assert wrapped.pack('fill_v', {'type': 'uint32', 'value': 5}) == b'\x00' + raw[1:]
+
+
+def test_variant_unknown_alternative():
+    with pytest.raises(ValueError, match='alternative `square`'):
+        shapes_abi.pack('fill_v', {'type': 'square', 'value': 1})
+
+
+def test_pack_batch():
+    transfers = [
+        {
+            'from': 'alice',
+            'to': 'bob',
+            'quantity': f'{i // 10000}.{i % 10000:04} EOS',
+            'memo': 'x' * (i % 7)
+        }
+        for i in range(10_000)
+    ]
+
+    buf, offsets = abi.pack_batch('transfer', transfers)
+
+    assert len(offsets) == len(transfers) + 1
+    assert offsets[0] == 0 and offsets[-1] == len(buf)
+
+    for i in (0, 1, 4321, len(transfers) - 1):
+        blob = buf[offsets[i]:offsets[i + 1]]
+        assert blob == abi.pack('transfer', transfers[i])
+        assert abi.unpack('transfer', blob)['memo'] == transfers[i]['memo']
+
+    assert abi.pack_batch('transfer', []) == (b'', [0])
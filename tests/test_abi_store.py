@@ -0,0 +1,80 @@
+import json
+
+import pytest
+
+from antelope_rs import ABI, AbiStore
+
+
+def counter_abi(field_type: str) -> dict:
+    return {
+        'version': 'eosio::abi/1.1',
+        'types': [],
+        'structs': [
+            {
+                'name': 'bump',
+                'base': '',
+                'fields': [{'name': 'by', 'type': field_type}]
+            }
+        ],
+        'actions': [
+            {'name': 'bump', 'type': 'bump', 'ricardian_contract': ''}
+        ],
+        'tables': [],
+        'ricardian_clauses': [],
+        'error_messages': [],
+        'abi_extensions': [],
+        'variants': []
+    }
+
+
+def test_isolated_stores():
+    testnet = AbiStore()
+    mainnet = AbiStore()
+
+    testnet.load('counter', counter_abi('uint8'))
+    mainnet.load('counter', counter_abi('uint64'))
+
+    assert testnet.pack('counter', 'bump', {'by': 1}) == b'\x01'
+    assert mainnet.pack('counter', 'bump', {'by': 1}) == b'\x01' + bytes(7)
+    assert testnet.unpack('counter', 'bump', b'\x02') == {'by': 2}
+
+    assert testnet.unload('counter')
+    assert 'counter' not in testnet
+    assert 'counter' in mainnet
+
+
+@pytest.mark.parametrize(
+    'form',
+    (
+        lambda d: ABI.from_dict(d),
+        lambda d: json.dumps(d),
+        lambda d: d,
+        lambda d: ABI.from_dict(d).encode(),
+    ),
+    ids=('abi', 'str', 'dict', 'packed')
+)
+def test_load_forms(form):
+    store = AbiStore()
+    store.load('counter', form(counter_abi('uint16')))
+
+    assert store.get('counter') == ABI.from_dict(counter_abi('uint16'))
+    assert store.pack('counter', 'bump', {'by': 1}) == b'\x01\x00'
+
+
+def test_list_and_get():
+    store = AbiStore()
+    abi = ABI.from_dict(counter_abi('uint8'))
+
+    store.load('zeta', abi)
+    store.load('alpha', counter_abi('uint32'))
+
+    assert store.list() == ['alpha', 'zeta']
+    assert len(store) == 2
+    # loaded objects are kept as is, not copied
+    assert store.get('zeta') is abi
+
+    assert not store.unload('missing')
+    with pytest.raises(KeyError, match='No ABI loaded for `missing`'):
+        store.get('missing')
+    with pytest.raises(KeyError):
+        store.unpack('missing', 'bump', b'\x01')
@@ -0,0 +1,239 @@
+from decimal import Decimal
+
+import pytest
+
+from antelope_rs import Asset, ExtendedAsset, sum_assets
+from antelope_rs._lowlevel import asset_max_amount
+
+
+def test_eq_requires_same_precision():
+    a = Asset.try_from('1.0 EOS')
+    b = Asset.try_from('1.0000 EOS')
+
+    assert a != b
+    assert a.equals_value(b)
+    assert b.equals_value(a)
+
+
+def test_equals_value_requires_same_code():
+    a = Asset.try_from('1.0000 EOS')
+    b = Asset.try_from('1.0000 TLOS')
+
+    assert not a.equals_value(b)
+
+
+def test_equals_value_negative():
+    a = Asset.try_from('-2.50 EOS')
+    b = Asset.try_from('-2.5000 EOS')
+
+    assert a.equals_value(b)
+    assert not a.equals_value(Asset.try_from('2.5000 EOS'))
+
+
+def test_to_extended():
+    ext = Asset.try_from('1.0000 EOS').to_extended('eosio.token')
+
+    assert str(ext) == '1.0000 EOS@eosio.token'
+
+
+def test_ordering():
+    small = Asset.try_from('1.0000 EOS')
+    big = Asset.try_from('2.0000 EOS')
+
+    assert small < big
+    assert big >= small
+    assert small <= small
+    assert sorted([big, small]) == [small, big]
+    assert max([small, big]) == big
+
+
+def test_ordering_requires_same_symbol():
+    with pytest.raises(ValueError, match='4,EOS and 4,TLOS'):
+        Asset.try_from('1.0000 EOS') < Asset.try_from('1.0000 TLOS')
+
+    with pytest.raises(ValueError, match='different symbols'):
+        Asset.try_from('1.0 EOS') < Asset.try_from('1.0000 EOS')
+
+
+def test_extended_ordering():
+    small = ExtendedAsset.from_str('1.0000 EOS@eosio.token')
+    big = ExtendedAsset.from_str('2.0000 EOS@eosio.token')
+
+    assert small < big
+    assert sorted([big, small]) == [small, big]
+
+    with pytest.raises(ValueError, match='different symbol or contract'):
+        small < ExtendedAsset.from_str('2.0000 EOS@fake.token')
+
+    with pytest.raises(ValueError, match='different symbol or contract'):
+        small < ExtendedAsset.from_str('2.0000 TLOS@eosio.token')
+
+
+def test_neg_abs_zero():
+    zero = Asset.try_from('0.0000 EOS')
+
+    assert -zero == zero
+    assert abs(zero) == zero
+
+
+def test_neg_abs_max_magnitude():
+    sym = Asset.try_from('0.0000 EOS').symbol
+    max_asset = Asset(asset_max_amount, sym)
+    min_asset = Asset(-asset_max_amount, sym)
+
+    assert -max_asset == min_asset
+    assert -min_asset == max_asset
+    assert abs(min_asset) == max_asset
+    assert abs(max_asset) == max_asset
+
+
+def test_sum_assets():
+    assets = [Asset.try_from(f'{i}.0000 EOS') for i in range(5)]
+
+    assert sum_assets(assets) == Asset.try_from('10.0000 EOS')
+
+    with pytest.raises(ValueError, match='index 2'):
+        sum_assets([assets[0], assets[1], Asset.try_from('1.0000 TLOS')])
+
+    with pytest.raises(ValueError, match='overflow'):
+        sum_assets([Asset(asset_max_amount, assets[0].symbol)] * 2)
+
+
+def test_hash():
+    a = Asset.try_from('1.0000 EOS')
+    b = Asset.try_from('1.0000 EOS')
+
+    assert hash(a) == hash(b)
+    assert hash(a) != hash(Asset.try_from('1.0 EOS'))
+
+    assets = {a, b, Asset.try_from('2.0000 EOS')}
+    assert len(assets) == 2
+    assert a in assets
+
+    meta = {a: 'balance'}
+    assert meta[b] == 'balance'
+
+
+def test_precision_and_symbol_code():
+    asset = Asset.try_from('1.0000 EOS')
+
+    assert asset.precision == 4
+    assert str(asset.symbol_code) == 'EOS'
+    assert asset.symbol_code == asset.symbol.code
+
+
+def test_extended_arithmetic_requires_same_contract():
+    a = ExtendedAsset.from_str('1.0000 EOS@eosio.token')
+    b = ExtendedAsset.from_str('1.0000 EOS@otherthing')
+
+    assert str(a + a) == '2.0000 EOS@eosio.token'
+    assert str(a - a) == '0.0000 EOS@eosio.token'
+
+    with pytest.raises(ValueError, match='eosio.token and otherthing'):
+        a + b
+
+    with pytest.raises(ValueError, match='eosio.token and otherthing'):
+        a - b
+
+
+@pytest.mark.parametrize(
+    'asset_str',
+    ('1.0000 EOS', '-0.5 TLOS', '0 ZERO')
+)
+def test_bytes_roundtrip_eq_hash(asset_str: str):
+    asset = Asset.try_from(asset_str)
+    decoded = Asset.from_bytes(asset.encode())
+
+    assert decoded == asset
+    assert hash(decoded) == hash(asset)
+    assert Asset.try_from(asset.encode()) == asset
+
+
+@pytest.mark.parametrize(
+    'ext_str',
+    ('1.0000 EOS@eosio.token', '-0.5 TLOS@eosio.token', '0 ZERO@zero.token')
+)
+def test_extended_bytes_roundtrip_eq_hash(ext_str: str):
+    ext = ExtendedAsset.from_str(ext_str)
+    decoded = ExtendedAsset.from_bytes(ext.encode())
+
+    assert decoded == ext
+    assert hash(decoded) == hash(ext)
+    assert ExtendedAsset.try_from(ext.encode()) == ext
+    assert len({ext, decoded}) == 1
+
+
+def test_to_dict_roundtrip():
+    asset = Asset.try_from('-2.2500 EOS')
+    d = asset.to_dict()
+
+    assert d == {'amount': -22500, 'symbol': '4,EOS'}
+    assert Asset.from_dict(d) == asset
+
+
+def test_extended_to_dict_roundtrip():
+    ext = ExtendedAsset.from_str('1.0000 EOS@eosio.token')
+    d = ext.to_dict()
+
+    assert d == {
+        'quantity': {'amount': 10000, 'symbol': '4,EOS'},
+        'contract': 'eosio.token'
+    }
+    assert ExtendedAsset.from_dict(d) == ext
+
+
+@pytest.mark.parametrize(
+    'value,amount',
+    (
+        ('1.5', 15000),
+        ('-2.25', -22500),
+        ('1.23456', 12346),
+        ('-1.23455', -12346),
+        ('0', 0),
+        ('3', 30000),
+    )
+)
+def test_from_decimal(value: str, amount: int):
+    asset = Asset.from_decimal(Decimal(value), '4,FOO')
+
+    assert asset.amount == amount
+    assert str(asset.symbol) == '4,FOO'
+
+
+def test_from_decimal_out_of_range():
+    with pytest.raises(ValueError, match='out of asset amount range'):
+        Asset.from_decimal(Decimal(asset_max_amount) + 1, '0,FOO')
+
+
+def test_from_ints():
+    assert Asset.from_ints(10000, 4, 'EOS') == Asset.try_from('1.0000 EOS')
+    assert Asset.from_ints(-5, 0, 'FOO') == Asset.try_from('-5 FOO')
+
+    with pytest.raises(ValueError):
+        Asset.from_ints(1, 19, 'EOS')
+
+    with pytest.raises(ValueError):
+        Asset.from_ints(1, 4, 'eos')
+
+
+@pytest.mark.parametrize(
+    'asset_str,expected',
+    (
+        ('1 FOO', '1'),
+        ('1.0000 EOS', '1.0000'),
+        ('0.0001 EOS', '0.0001'),
+        ('-0.0500 EOS', '-0.0500'),
+        ('-12 FOO', '-12'),
+    )
+)
+def test_to_amount_string(asset_str: str, expected: str):
+    assert Asset.try_from(asset_str).to_amount_string() == expected
+
+
+@pytest.mark.parametrize('asset_str', ('1e3 EOS', '1E+5 EOS', '2.5e-1 EOS'))
+def test_scientific_notation_rejected(asset_str: str):
+    with pytest.raises(ValueError, match='scientific notation not supported'):
+        Asset.try_from(asset_str)
+
+    with pytest.raises(ValueError, match='scientific notation not supported'):
+        ExtendedAsset.from_str(f'{asset_str}@eosio.token')
@@ -0,0 +1,133 @@
+import base64
+import hashlib
+
+import pytest
+
+from antelope_rs import Checksum160, Checksum256, Checksum512
+
+
+def reference_merkle(ids: list[bytes]) -> bytes:
+    if not ids:
+        return bytes(32)
+
+    while len(ids) > 1:
+        if len(ids) % 2:
+            ids.append(ids[-1])
+
+        ids = [
+            hashlib.sha256(
+                bytes([ids[i][0] & 0x7f]) + ids[i][1:] +
+                bytes([ids[i + 1][0] | 0x80]) + ids[i + 1][1:]
+            ).digest()
+            for i in range(0, len(ids), 2)
+        ]
+
+    return ids[0]
+
+
+def test_merkle_root():
+    leaves = [hashlib.sha256(bytes([i])).digest() for i in range(5)]
+
+    assert Checksum256.merkle_root([]).raw == bytes(32)
+    assert Checksum256.merkle_root(leaves[:1]).raw == leaves[0]
+
+    for n in range(2, len(leaves) + 1):
+        root = Checksum256.merkle_root(leaves[:n])
+        assert root.raw == reference_merkle(leaves[:n])
+
+    # pinned, independent of `reference_merkle`
+    assert Checksum256.merkle_root(leaves[:2]).hex() == (
+        'a6a85214b21c86180a14566655aabbc08dc664c75c8e66dd5370e69ab77ea8cb'
+    )
+    assert Checksum256.merkle_root(leaves).hex() == (
+        'bc33fee5df34b861c1893f064ee2cddbe460ff38123f7ea4b239b7775761bb5f'
+    )
+
+
+def test_bytes():
+    digest = hashlib.sha256(b'antelope').digest()
+    sum256 = Checksum256.from_bytes(digest)
+
+    assert bytes(sum256) == digest
+    assert bytes(Checksum160.from_bytes(digest[:20])) == digest[:20]
+    assert bytes(Checksum512.from_bytes(digest * 2)) == digest * 2
+
+    assert hashlib.sha256(bytes(sum256)).digest() == hashlib.sha256(digest).digest()
+
+
+def has_buffer_protocol() -> bool:
+    try:
+        memoryview(Checksum160.from_bytes(bytes(20)))
+
+    except TypeError:
+        return False
+
+    return True
+
+
+# abi3 builds only get the buffer protocol with a Python 3.11+ floor
+@pytest.mark.skipif(not has_buffer_protocol(), reason='built without buffer protocol')
+@pytest.mark.parametrize(
+    'cls,size',
+    ((Checksum160, 20), (Checksum256, 32), (Checksum512, 64)),
+    ids=('160', '256', '512')
+)
+def test_buffer_protocol(cls, size: int):
+    data = bytes(range(size))
+    csum = cls.from_bytes(data)
+
+    view = memoryview(csum)
+    assert view.readonly
+    assert view.tobytes() == data
+    assert hashlib.sha256(csum).digest() == hashlib.sha256(data).digest()
+    assert bytearray(csum) == data
+
+    with pytest.raises(TypeError):
+        view[0] = 1
+
+
+def test_hash_and_ordering():
+    digests = [hashlib.sha256(bytes([i])).digest() for i in range(8)]
+    sums = [Checksum256.from_bytes(d) for d in digests]
+
+    assert hash(sums[0]) == hash(Checksum256.from_bytes(digests[0]))
+    assert len(set(sums + [Checksum256.from_bytes(d) for d in digests])) == 8
+
+    assert [s.raw for s in sorted(sums)] == sorted(digests)
+
+    low = Checksum160.from_bytes(bytes(20))
+    high = Checksum160.from_bytes(bytes(19) + b'\x01')
+    assert low < high and low <= high and high > low and high >= low
+    assert Checksum512.from_bytes(bytes(64)) < Checksum512.from_bytes(b'\x01' + bytes(63))
+
+
+def test_strict_hex():
+    # only hex digits, so it's also a valid base64 string
+    hex_str = '00' * 19 + 'ab'
+    assert base64.b64decode(hex_str)
+
+    sum160 = Checksum160.from_hex(hex_str)
+    assert sum160.raw == bytes(19) + b'\xab'
+    assert sum160.hex() == hex_str
+    assert Checksum160.from_hex(hex_str.upper()) == sum160
+
+    digest = hashlib.sha256(b'antelope').digest()
+    assert Checksum256.from_hex(digest.hex()).raw == digest
+    assert Checksum512.from_hex((digest * 2).hex()).hex() == (digest * 2).hex()
+
+    with pytest.raises(ValueError, match='Expected 20 bytes'):
+        Checksum160.from_hex(hex_str + '00')
+
+    with pytest.raises(ValueError, match='Invalid hex'):
+        Checksum160.from_hex('zz' * 20)
+
+
+@pytest.mark.parametrize('data', (b'', b'abc'), ids=('empty', 'abc'))
+def test_hash_vectors(data: bytes):
+    assert Checksum256.hash(data).raw == hashlib.sha256(data).digest()
+    assert Checksum512.hash512(data).raw == hashlib.sha512(data).digest()
+
+
+def test_hash160_vectors():
+    assert Checksum160.hash160(b'').hex() == '9c1185a5c5e9fc54612808977ee8f548b2258d31'
+    assert Checksum160.hash160(b'abc').hex() == '8eb208f7e05d987a9b044a8e98c6b087f15a0bfc'
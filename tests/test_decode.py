@@ -0,0 +1,618 @@
+import struct
+from typing import Optional, Union
+
+import pytest
+
+from antelope_rs import (
+    AbiStore,
+    Asset,
+    Checksum160,
+    Checksum256,
+    Checksum512,
+    Decoder,
+    ExtendedAsset,
+    Name,
+    PrivateKey,
+    PublicKey,
+    Signature,
+    benchmark_decode,
+)
+
+from conftest import make_abi
+
+
+linked_list_abi = make_abi([
+    {
+        'name': 'node',
+        'base': '',
+        'fields': [
+            {'name': 'value', 'type': 'uint32'},
+            {'name': 'next', 'type': 'node?'}
+        ]
+    }
+])
+
+
+def pack_list(length: int) -> bytes:
+    buf = b''
+    for i in range(length):
+        buf += struct.pack('<I', i)
+        buf += b'\x01' if i < length - 1 else b'\x00'
+    return buf
+
+
+def test_linked_list_roundtrip():
+    val = linked_list_abi.unpack('node', pack_list(3))
+
+    assert val == {
+        'value': 0,
+        'next': {
+            'value': 1,
+            'next': {'value': 2, 'next': None}
+        }
+    }
+    assert linked_list_abi.pack('node', val) == pack_list(3)
+
+
+def test_linked_list_max_depth():
+    buf = pack_list(16)
+
+    linked_list_abi.unpack('node', buf, max_depth=16)
+
+    with pytest.raises(ValueError, match='max decode depth of 15'):
+        linked_list_abi.unpack('node', buf, max_depth=15)
+
+
+def test_linked_list_default_max_depth():
+    with pytest.raises(ValueError, match='max decode depth'):
+        linked_list_abi.unpack('node', pack_list(10_000))
+
+
+optional_bool_abi = make_abi([
+    {
+        'name': 'vote',
+        'base': '',
+        'fields': [
+            {'name': 'approve', 'type': 'bool?'},
+            {'name': 'weight', 'type': 'uint8'}
+        ]
+    }
+])
+
+
+@pytest.mark.parametrize(
+    'raw,expected',
+    (
+        (b'\x00\x07', None),
+        (b'\x01\x00\x07', False),
+        (b'\x01\x01\x07', True),
+    ),
+    ids=('none', 'false', 'true')
+)
+def test_optional_bool(raw: bytes, expected: Optional[bool]):
+    decoder = Decoder(raw)
+    val = decoder.unpack(optional_bool_abi, 'vote')
+
+    assert val['approve'] is expected
+    assert val['weight'] == 7
+    assert decoder.remaining == 0
+
+    assert optional_bool_abi.pack('vote', val) == raw
+
+
+optional_struct_abi = make_abi([
+    {
+        'name': 'empty',
+        'base': '',
+        'fields': []
+    },
+    {
+        'name': 'settings',
+        'base': '',
+        'fields': [
+            {'name': 'quorum', 'type': 'uint8'}
+        ]
+    },
+    {
+        'name': 'proposal',
+        'base': '',
+        'fields': [
+            {'name': 'meta', 'type': 'empty?'},
+            {'name': 'settings', 'type': 'settings?'}
+        ]
+    }
+])
+
+
+@pytest.mark.parametrize(
+    'raw,expected',
+    (
+        (b'\x00\x00', {'meta': None, 'settings': None}),
+        (b'\x01\x00', {'meta': {}, 'settings': None}),
+        (b'\x00\x01\x00', {'meta': None, 'settings': {'quorum': 0}}),
+        (b'\x01\x01\x00', {'meta': {}, 'settings': {'quorum': 0}}),
+    ),
+    ids=('absent', 'present_empty', 'present_default', 'both_present')
+)
+def test_optional_struct(raw: bytes, expected: dict):
+    val = optional_struct_abi.unpack('proposal', raw)
+
+    assert val == expected
+    assert isinstance(val['meta'], dict) == (expected['meta'] is not None)
+
+    assert optional_struct_abi.pack('proposal', val) == raw
+
+
+blob_abi = make_abi([
+    {
+        'name': 'blob',
+        'base': '',
+        'fields': [
+            {'name': 'chunks', 'type': 'bytes[]'}
+        ]
+    }
+])
+
+
+def test_output_budget():
+    val = {'chunks': [bytes(10)] * 3}
+    raw = blob_abi.pack('blob', val)
+
+    # 3 array elements + 3 * (1 length byte + 10 data bytes)
+    assert blob_abi.unpack('blob', raw, max_output=36) == val
+
+    with pytest.raises(ValueError, match='output budget of 35 exceeded'):
+        blob_abi.unpack('blob', raw, max_output=35)
+
+
+def test_iter_struct():
+    raw = optional_struct_abi.pack('proposal', {'meta': {}, 'settings': None})
+    decoder = Decoder(raw + b'\xff')
+
+    it = decoder.iter_struct(optional_struct_abi, 'proposal')
+
+    assert next(it) == ('meta', {})
+    assert decoder.pos == 1
+
+    assert list(it) == [('settings', None)]
+    assert decoder.remaining == 1
+
+
+def test_iter_struct_requires_struct():
+    with pytest.raises(TypeError):
+        Decoder(b'').iter_struct(optional_struct_abi, 'settings[]')
+
+
+chunked_abi = make_abi([
+    {
+        'name': 'chunk',
+        'base': '',
+        'fields': [
+            {'name': 'index', 'type': 'uint8'},
+            {'name': 'data', 'type': 'uint8[]'}
+        ]
+    },
+    {
+        'name': 'upload',
+        'base': '',
+        'fields': [
+            {'name': 'header', 'type': 'uint8[]'},
+            {'name': 'chunks', 'type': 'chunk[]'}
+        ]
+    }
+])
+
+
+def test_nested_uint8_array_as_bytes():
+    raw = (
+        b'\x02\xca\xfe' +
+        b'\x02' +
+        b'\x00\x03abc' +
+        b'\x01\x00'
+    )
+
+    val = chunked_abi.unpack('upload', raw)
+
+    assert val == {
+        'header': b'\xca\xfe',
+        'chunks': [
+            {'index': 0, 'data': b'abc'},
+            {'index': 1, 'data': b''}
+        ]
+    }
+    assert all(isinstance(c['data'], bytes) for c in val['chunks'])
+
+    assert chunked_abi.pack('upload', val) == raw
+    assert chunked_abi.pack('upload', {
+        'header': [0xca, 0xfe],
+        'chunks': [
+            {'index': 0, 'data': list(b'abc')},
+            {'index': 1, 'data': []}
+        ]
+    }) == raw
+
+
+ext_abi = make_abi([
+    {
+        'name': 'deposit',
+        'base': '',
+        'fields': [
+            {'name': 'funds', 'type': 'extended_asset'},
+            {'name': 'refunds', 'type': 'extended_asset[]'}
+        ]
+    }
+])
+
+
+def test_extended_asset_decodes_to_proxy():
+    funds = ExtendedAsset.from_str('1.0000 EOS@eosio.token')
+    raw = ext_abi.pack('deposit', {'funds': str(funds), 'refunds': [funds]})
+
+    val = ext_abi.unpack('deposit', raw)
+
+    assert isinstance(val['funds'], ExtendedAsset)
+    assert val['funds'] == funds
+    assert val['funds'].quantity == Asset.from_str('1.0000 EOS')
+    assert val['funds'].contract == Name.from_str('eosio.token')
+    assert all(isinstance(r, ExtendedAsset) for r in val['refunds'])
+
+    assert ext_abi.pack('deposit', val) == raw
+
+
+crypto_abi = make_abi([
+    {
+        'name': 'proof',
+        'base': '',
+        'fields': [
+            {'name': 'key', 'type': 'public_key'},
+            {'name': 'sig', 'type': 'signature'},
+            {'name': 'c160', 'type': 'checksum160'},
+            {'name': 'c256', 'type': 'checksum256'},
+            {'name': 'c512', 'type': 'checksum512'}
+        ]
+    }
+])
+
+
+def test_crypto_types_decode_typed():
+    key = PrivateKey.generate('K1')
+    proof = {
+        'key': key.public_key,
+        'sig': key.sign(b'msg'),
+        'c160': Checksum160.from_bytes(bytes(20)),
+        'c256': Checksum256.hash(b'data'),
+        'c512': Checksum512.from_bytes(bytes(range(64)))
+    }
+    raw = crypto_abi.pack('proof', proof)
+
+    val = crypto_abi.unpack('proof', raw)
+
+    assert isinstance(val['key'], PublicKey)
+    assert isinstance(val['sig'], Signature)
+    assert isinstance(val['c160'], Checksum160)
+    assert isinstance(val['c256'], Checksum256)
+    assert isinstance(val['c512'], Checksum512)
+    assert val == proof
+
+    plain = crypto_abi.unpack('proof', raw, typed=False)
+
+    assert plain == {k: str(v) for k, v in proof.items()}
+    assert crypto_abi.pack('proof', plain) == raw
+
+
+wide_abi = make_abi([
+    {
+        'name': 'wide',
+        'base': '',
+        'fields': [
+            {'name': 'u', 'type': 'uint256'},
+            {'name': 'i', 'type': 'int256'}
+        ]
+    }
+])
+
+
+@pytest.mark.parametrize('u, i', [
+    (0, 0),
+    (2**256 - 1, 2**255 - 1),
+    (2**200 + 12345, -(2**255)),
+    (2**128, -(2**130) - 7)
+])
+def test_256_bit_int_roundtrip(u: int, i: int):
+    raw = wide_abi.pack('wide', {'u': u, 'i': i})
+
+    assert len(raw) == 64
+    assert raw == u.to_bytes(32, 'little') + i.to_bytes(32, 'little', signed=True)
+    assert wide_abi.unpack('wide', raw) == {'u': u, 'i': i}
+
+
+@pytest.mark.parametrize('val', [
+    {'u': 2**256, 'i': 0},
+    {'u': -1, 'i': 0},
+    {'u': 0, 'i': 2**255},
+    {'u': 0, 'i': -(2**255) - 1},
+])
+def test_256_bit_int_out_of_range(val: dict):
+    with pytest.raises(ValueError):
+        wide_abi.pack('wide', val)
+
+
+quad_abi = make_abi([
+    {
+        'name': 'quad',
+        'base': '',
+        'fields': [{'name': 'q', 'type': 'float128'}]
+    }
+])
+
+
+@pytest.mark.parametrize('val, raw', [
+    (0.0, bytes(16)),
+    (1.5, bytes(13) + b'\x80\xff\x3f'),
+    ('-2', bytes(15) + b'\xc0'),
+    # smallest f64 subnormal, 2 ** -1074, is a normal binary128
+    (5e-324, bytes(14) + b'\xcd\x3b'),
+    (float('inf'), bytes(14) + b'\xff\x7f'),
+])
+def test_float128_roundtrip(val: Union[float, str], raw: bytes):
+    assert quad_abi.pack('quad', {'q': val}) == raw
+
+    decoded = quad_abi.unpack('quad', raw)
+    assert decoded == {'q': raw}
+    assert quad_abi.pack('quad', decoded) == raw
+
+
+def test_float128_invalid():
+    with pytest.raises(ValueError):
+        quad_abi.pack('quad', {'q': 'not a float'})
+
+
+big_int_abi = make_abi([
+    {
+        'name': 'bigs',
+        'base': '',
+        'fields': [
+            {'name': 'u64', 'type': 'uint64'},
+            {'name': 'i64', 'type': 'int64'},
+            {'name': 'u128', 'type': 'uint128'},
+            {'name': 'i128', 'type': 'int128'}
+        ]
+    }
+])
+
+
+def test_big_ints_from_str():
+    val = {
+        'u64': 2**64 - 1,
+        'i64': -(2**63),
+        'u128': 2**128 - 1,
+        'i128': -(2**127)
+    }
+    raw = big_int_abi.pack('bigs', val)
+
+    assert big_int_abi.pack('bigs', {k: str(v) for k, v in val.items()}) == raw
+    assert raw[16:32] == b'\xff' * 16
+    assert big_int_abi.unpack('bigs', raw) == val
+
+
+@pytest.mark.parametrize('field, val', [
+    ('u128', str(2**128)),
+    ('u64', '-1'),
+    ('i64', str(2**63)),
+    ('i128', '12.5'),
+    ('u64', 'abc'),
+])
+def test_big_ints_from_str_invalid(field: str, val: str):
+    vals = {'u64': 0, 'i64': 0, 'u128': 0, 'i128': 0}
+    vals[field] = val
+
+    with pytest.raises(ValueError):
+        big_int_abi.pack('bigs', vals)
+
+
+order_abi = make_abi([
+    {
+        'name': 'extra',
+        'base': '',
+        'fields': [{'name': 'note', 'type': 'string'}]
+    },
+    {
+        'name': 'item',
+        'base': '',
+        'fields': [
+            {'name': 'id', 'type': 'uint32'},
+            {'name': 'extra', 'type': 'extra?'}
+        ]
+    },
+    {
+        'name': 'order',
+        'base': '',
+        'fields': [{'name': 'items', 'type': 'item[]'}]
+    }
+])
+
+
+def test_encode_error_path():
+    val = {
+        'items': [
+            {'id': 0, 'extra': None},
+            {'id': 1, 'extra': {}}
+        ]
+    }
+
+    with pytest.raises(ValueError, match=r'at `order\.items\[1\]\.extra\.some`: missing `note`'):
+        order_abi.pack('order', val)
+
+
+def test_decode_error_path():
+    raw = order_abi.pack('order', {
+        'items': [
+            {'id': 0, 'extra': None},
+            {'id': 1, 'extra': {'note': 'hello'}}
+        ]
+    })
+
+    # cut inside `note`
+    with pytest.raises(ValueError, match=r'at `order\.items\[1\]\.extra\.some\.note`'):
+        order_abi.unpack('order', raw[:-2])
+
+
+inherit_abi = make_abi([
+    {
+        'name': 'root',
+        'base': '',
+        'fields': [{'name': 'a', 'type': 'uint8'}]
+    },
+    {
+        'name': 'middle',
+        'base': 'root',
+        'fields': [{'name': 'b', 'type': 'uint16'}]
+    },
+    {
+        'name': 'leaf',
+        'base': 'middle',
+        'fields': [{'name': 'c', 'type': 'string'}]
+    }
+])
+
+
+def test_struct_inheritance_chain():
+    val = {'a': 1, 'b': 2, 'c': 'x'}
+    raw = inherit_abi.pack('leaf', val)
+
+    # base fields first, root to leaf
+    assert raw == b'\x01' + b'\x02\x00' + b'\x01x'
+
+    decoded = inherit_abi.unpack('leaf', raw)
+    assert decoded == val
+    assert list(decoded) == ['a', 'b', 'c']
+
+    with pytest.raises(ValueError, match=r'at `leaf`: missing `a`'):
+        inherit_abi.pack('leaf', {'b': 2, 'c': 'x'})
+
+
+def test_strict_trailing_bytes():
+    raw = inherit_abi.pack('leaf', {'a': 1, 'b': 2, 'c': 'x'})
+
+    assert inherit_abi.unpack('leaf', raw, strict=True) == {'a': 1, 'b': 2, 'c': 'x'}
+    # lenient by default
+    assert inherit_abi.unpack('leaf', raw + b'\x00') == {'a': 1, 'b': 2, 'c': 'x'}
+
+    with pytest.raises(ValueError, match='1 trailing bytes after decoding `leaf`'):
+        inherit_abi.unpack('leaf', raw + b'\x00', strict=True)
+
+
+def test_strict_binary_extension():
+    ext_abi = make_abi([
+        {
+            'name': 'versioned',
+            'base': '',
+            'fields': [
+                {'name': 'a', 'type': 'uint8'},
+                {'name': 'b', 'type': 'uint8$'}
+            ]
+        }
+    ])
+
+    # absent & present extension tails both consume the whole buffer
+    assert ext_abi.unpack('versioned', b'\x01', strict=True) == {'a': 1, 'b': None}
+    assert ext_abi.unpack('versioned', b'\x01\x02', strict=True) == {'a': 1, 'b': 2}
+
+    with pytest.raises(ValueError, match='1 trailing bytes'):
+        ext_abi.unpack('versioned', b'\x01\x02\x03', strict=True)
+
+
+@pytest.mark.parametrize('cut', [1, 2, 5])
+def test_truncated_input(cut: int):
+    raw = big_int_abi.pack('bigs', {'u64': 1, 'i64': 2, 'u128': 3, 'i128': 4})
+
+    with pytest.raises(ValueError, match='unexpected end of buffer while decoding `int128`'):
+        big_int_abi.unpack('bigs', raw[:-cut])
+
+    with pytest.raises(ValueError, match='unexpected end of buffer'):
+        Decoder(raw[:-cut]).skip(big_int_abi, 'bigs')
+
+
+def test_truncated_modifiers():
+    with pytest.raises(ValueError, match='`optional-flag` at `item.extra`'):
+        order_abi.unpack('item', b'\x00' * 4)
+
+    with pytest.raises(ValueError, match='`array-length` at `order.items`'):
+        order_abi.unpack('order', b'')
+
+
+def test_truncated_length_prefixed():
+    # header declares 4 bytes, only 2 follow
+    with pytest.raises(ValueError, match='`uint8\\[\\]` at `upload.header`'):
+        chunked_abi.unpack('upload', b'\x04\xca\xfe')
+
+    with pytest.raises(ValueError, match='unexpected end of buffer'):
+        Decoder(b'\x04\xca\xfe').skip(chunked_abi, 'upload')
+
+    bytes_abi = make_abi([
+        {
+            'name': 'blob',
+            'base': '',
+            'fields': [{'name': 'data', 'type': 'bytes'}]
+        }
+    ])
+    raw = bytes_abi.pack('blob', {'data': b'abcdef'})
+
+    with pytest.raises(ValueError, match='`bytes` at `blob.data`'):
+        bytes_abi.unpack('blob', raw[:-3])
+
+    # unterminated varuint length prefix
+    with pytest.raises(ValueError, match='unexpected end of buffer'):
+        bytes_abi.unpack('blob', b'\x80')
+
+
+nested_mods_abi = make_abi([
+    {
+        'name': 'mods',
+        'base': '',
+        'fields': [
+            {'name': 'opt_list', 'type': 'uint32[]?'},
+            {'name': 'list_opt', 'type': 'uint32?[]'}
+        ]
+    }
+])
+
+
+@pytest.mark.parametrize('val, raw', [
+    (
+        {'opt_list': [1, 2], 'list_opt': []},
+        b'\x01\x02' + struct.pack('<II', 1, 2) + b'\x00'
+    ),
+    (
+        {'opt_list': None, 'list_opt': [None, 7, None]},
+        b'\x00' + b'\x03' + b'\x00' + b'\x01' + struct.pack('<I', 7) + b'\x00'
+    ),
+    (
+        {'opt_list': [], 'list_opt': [3, None, 4]},
+        b'\x01\x00' + b'\x03'
+        + b'\x01' + struct.pack('<I', 3) + b'\x00' + b'\x01' + struct.pack('<I', 4)
+    ),
+])
+def test_nested_modifiers(val: dict, raw: bytes):
+    assert nested_mods_abi.pack('mods', val) == raw
+    assert nested_mods_abi.unpack('mods', raw) == val
+    assert Decoder(raw).skip(nested_mods_abi, 'mods') == len(raw)
+
+
+def test_benchmark_decode():
+    store = AbiStore()
+    store.load('uploader', chunked_abi)
+    raw = b'\x02\xca\xfe' + b'\x01' + b'\x00\x03abc'
+
+    micros = benchmark_decode(store, 'uploader', 'upload', raw, 10)
+    assert isinstance(micros, float)
+    assert micros > 0
+
+    with pytest.raises(ValueError):
+        benchmark_decode(store, 'uploader', 'upload', raw[:-1], 10)
+
+    with pytest.raises(ValueError, match='iterations must be greater than 0'):
+        benchmark_decode(store, 'uploader', 'upload', raw, 0)
+
+    with pytest.raises(KeyError, match='No ABI loaded for `missing`'):
+        benchmark_decode(store, 'missing', 'upload', raw, 10)
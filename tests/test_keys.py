@@ -0,0 +1,139 @@
+import pytest
+
+from antelope_rs import PrivateKey, PublicKey, Signature
+
+
+# well known development key pair
+dev_priv = 'PVT_K1_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V'
+dev_pub = 'PUB_K1_6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5BoDq63'
+dev_legacy_priv = '5KQwrPbwdL6PhXujxW37FSSQZ1JiwsST4cqQzDeyXtP79zkvFD3'
+dev_legacy_pub = 'EOS6MRyAjQq8ud7hVNYcfnVPJqcVpscN5So8BhtHuGYqET5GDW5CV'
+
+
+def test_from_seed_deterministic():
+    a = PrivateKey.from_seed(b'test seed')
+    b = PrivateKey.from_seed(b'test seed')
+
+    assert a == b
+    assert a.get_public() == b.get_public()
+    assert a != PrivateKey.from_seed(b'other seed')
+
+
+def test_from_seed_key_type():
+    k1 = PrivateKey.from_seed(b'test seed', 'K1')
+    r1 = PrivateKey.from_seed(b'test seed', 'R1')
+
+    assert k1 == PrivateKey.from_seed(b'test seed')
+    assert k1 != r1
+    assert str(r1).startswith('PVT_R1_')
+
+    with pytest.raises(ValueError):
+        PrivateKey.from_seed(b'test seed', 'WA')
+
+
+@pytest.mark.parametrize('key_type', ('K1', 'R1'))
+def test_generate(key_type: str):
+    a = PrivateKey.generate(key_type)
+    b = PrivateKey.generate(key_type)
+
+    assert a != b
+    assert str(a).startswith(f'PVT_{key_type}_')
+    assert PrivateKey.from_str(str(a)) == a
+
+
+def test_generate_invalid_type():
+    with pytest.raises(ValueError):
+        PrivateKey.generate('WA')
+
+
+def test_public_key_derivation():
+    key = PrivateKey.from_str(dev_priv)
+
+    assert str(key.public_key) == dev_pub
+    assert key.public_key == key.get_public() == PublicKey.from_str(dev_pub)
+
+    r1 = PrivateKey.generate('R1')
+    assert str(r1.public_key).startswith('PUB_R1_')
+
+
+def test_sign_verify():
+    key = PrivateKey.generate('K1')
+    msg = b'arbitrary message'
+
+    sig = key.sign(msg)
+
+    assert key.public_key.verify(msg, sig)
+    assert key.public_key.verify(msg, str(sig))
+    assert not key.public_key.verify(b'other message', sig)
+    assert not PrivateKey.generate('K1').public_key.verify(msg, sig)
+
+
+def test_signature_recover():
+    key = PrivateKey.from_str(dev_priv)
+    msg = b'recover me'
+
+    assert key.sign(msg).recover(msg) == PublicKey.from_str(dev_pub)
+
+    other = PrivateKey.generate('K1')
+    assert other.sign(msg).recover(msg) == other.public_key
+    assert other.sign(msg).recover(b'tampered') != other.public_key
+
+
+def test_signature_recover_rejects():
+    msg = b'recover me'
+    raw = bytearray(PrivateKey.from_str(dev_priv).sign(msg).encode())
+
+    # key type byte followed by an out of range recovery id
+    raw[1] = 0
+    with pytest.raises(ValueError, match='Malformed signature'):
+        Signature.from_bytes(bytes(raw)).recover(msg)
+
+    # WA: compact signature, empty auth data & client json
+    wa_raw = b'\x02' + bytes([31]) + bytes(64) + b'\x00' + b'\x00'
+    with pytest.raises(ValueError):
+        Signature.from_bytes(wa_raw).recover(msg)
+
+
+@pytest.mark.parametrize('key_type', ('K1', 'R1'))
+def test_key_type(key_type: str):
+    key = PrivateKey.generate(key_type)
+    sig = key.sign(b'msg')
+
+    assert key.type == key_type
+    assert key.public_key.type == key_type
+    assert Signature.from_str(str(sig)).type == key_type
+
+    assert PublicKey.from_str(str(key.public_key)).type == key_type
+
+
+def test_known_key_type():
+    assert PrivateKey.from_str(dev_priv).type == 'K1'
+    assert PublicKey.from_str(dev_pub).type == 'K1'
+
+
+def test_key_set_dedupe_and_order():
+    keys = [PrivateKey.generate('K1') for _ in range(3)]
+    pubs = [k.public_key for k in keys]
+
+    assert len(set(pubs + [PublicKey.from_str(str(p)) for p in pubs])) == 3
+    assert len(set(keys + [PrivateKey.from_str(str(k)) for k in keys])) == 3
+
+    assert [p.encode() for p in sorted(pubs)] == sorted(p.encode() for p in pubs)
+
+    # same scalar on a different curve is a different key
+    k1 = PrivateKey.from_seed(b'seed', 'K1')
+    r1 = PrivateKey.from_seed(b'seed', 'R1')
+    assert k1 != r1 and k1 < r1
+    assert len({k1, r1}) == 2
+
+
+def test_legacy_string():
+    assert PrivateKey.from_str(dev_priv).to_legacy_string() == dev_legacy_priv
+    assert PublicKey.from_str(dev_pub).to_legacy_string() == dev_legacy_pub
+
+    r1 = PrivateKey.generate('R1')
+    with pytest.raises(ValueError, match='only defined for K1'):
+        r1.to_legacy_string()
+
+    with pytest.raises(ValueError, match='only defined for K1'):
+        r1.public_key.to_legacy_string()
@@ -1,8 +1,24 @@
+import json
+
 import pytest
 import msgspec
 
-from antelope_rs import Name
-from antelope_rs.codec import dec_hook, enc_hook
+from antelope_rs import (
+    ABI,
+    Asset,
+    Checksum160,
+    Checksum256,
+    Checksum512,
+    ExtendedAsset,
+    Name,
+    PrivateKey,
+    Symbol,
+    SymbolCode,
+    TimePoint,
+)
+from antelope_rs.codec import dec_hook, enc_hook, json_enc_hook
+
+from conftest import make_abi
 
 
 class Action(msgspec.Struct):
@@ -71,3 +87,44 @@ def test_action_struct(sample_input):
         convert_result_0, enc_hook=enc_hook
     )
     assert builtins_result_0 == sample_ints
+
+
+key = PrivateKey.random(0)
+
+abi = make_abi(
+    [{'name': 'ping', 'base': '', 'fields': [{'name': 'n', 'type': 'uint64'}]}],
+    actions=[{'name': 'ping', 'type': 'ping', 'ricardian_contract': ''}]
+)
+
+
+@pytest.mark.parametrize(
+    'obj',
+    (
+        Name.from_str('eosio.token'),
+        SymbolCode.from_str('EOS'),
+        Symbol.from_str('4,EOS'),
+        Asset.from_str('1.0000 EOS'),
+        ExtendedAsset.from_str('1.0000 EOS@eosio.token'),
+        Checksum160.from_bytes(bytes(range(20))),
+        Checksum256.from_bytes(bytes(range(32))),
+        Checksum512.from_bytes(bytes(range(64))),
+        key,
+        key.get_public(),
+        key.sign(b'msg'),
+        TimePoint.from_int(1_700_000_000_000_000),
+        abi,
+    ),
+    ids=lambda obj: type(obj).__name__
+)
+def test_to_json_value(obj):
+    value = obj.to_json_value()
+
+    assert msgspec.to_builtins(obj, enc_hook=json_enc_hook) == value
+    assert json.loads(msgspec.json.encode(obj, enc_hook=json_enc_hook)) == value
+
+    if isinstance(value, str):
+        assert value == str(obj)
+
+    if isinstance(obj, ABI):
+        assert value == json.loads(str(obj))
+        assert ABI.from_dict(value) == obj
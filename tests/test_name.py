@@ -0,0 +1,138 @@
+import pytest
+
+import antelope_rs._lowlevel as lowlevel
+
+from antelope_rs import Name
+
+
+def test_single_name_class():
+    assert Name is lowlevel.Name
+
+
+def test_hash_matches_value():
+    name = Name.from_str('eosio.token')
+
+    assert hash(name) == hash(Name.from_int(name.value()))
+    assert hash(name) == hash(Name.from_bytes(name.encode()))
+    assert len({name, Name.from_int(name.value())}) == 1
+
+
+def test_padded_string():
+    name = Name.from_str('eosio.token')
+
+    assert name.to_padded_string() == 'eosio.token..'
+    assert Name.from_padded_string('eosio.token..') == name
+    assert Name.from_padded_string('eosio.token') == name
+    assert Name.from_padded_string('.............') == Name.from_int(0)
+
+    with pytest.raises(ValueError):
+        Name.from_padded_string('eosio.token...')
+
+
+@pytest.mark.parametrize(
+    'name_str',
+    ('', 'a', 'eosio', 'eosio.token', 'zzzzzzzzzzzzj', '1.2.3.4.5')
+)
+def test_str_roundtrip(name_str: str):
+    name = Name.from_str(name_str)
+
+    assert str(name) == name_str
+    assert Name.from_int(name.value()) == name
+    assert Name.from_bytes(name.encode()) == name
+    assert str(Name.from_int(int(name))) == name_str
+
+
+@pytest.mark.parametrize(
+    'value,name_str',
+    (
+        (0, ''),
+        (1, '............1'),
+        (2 ** 63, 'k'),
+        (2 ** 64 - 1, 'zzzzzzzzzzzzj'),
+        (0x5530ea033482a600, 'eosio.token'),
+    )
+)
+def test_from_int_roundtrip(value: int, name_str: str):
+    name = Name.from_int(value)
+
+    assert str(name) == name_str
+    assert Name.from_str(str(name)) == name
+    assert name.value() == value
+
+
+@pytest.mark.parametrize('value', (-1, 2 ** 64))
+def test_from_int_rejects_out_of_range(value: int):
+    with pytest.raises(OverflowError):
+        Name.from_int(value)
+
+
+@pytest.mark.parametrize(
+    'name_str,prefix,suffix',
+    (
+        ('eosio.token', 'eosio', 'token'),
+        ('a.b.c', 'a.b', 'c'),
+        ('eosio', 'eosio', 'eosio'),
+    )
+)
+def test_prefix_suffix(name_str: str, prefix: str, suffix: str):
+    name = Name.from_str(name_str)
+
+    assert name.prefix() == Name.from_str(prefix)
+    assert name.suffix() == Name.from_str(suffix)
+
+
+@pytest.mark.parametrize(
+    'name_str,affix,starts,ends',
+    (
+        ('eosio.token', 'eosio.', True, False),
+        ('eosio.token', '.token', False, True),
+        ('a.b.c', 'a.b', True, False),
+        ('eosio', '', True, True),
+        ('', '', True, True),
+        ('eosio', 'eosio.token', False, False),
+    )
+)
+def test_startswith_endswith(name_str: str, affix: str, starts: bool, ends: bool):
+    name = Name.from_str(name_str)
+
+    assert name.startswith(affix) is starts
+    assert name.endswith(affix) is ends
+
+
+def test_ordering_by_value():
+    names = [Name.from_str(n) for n in ('zzz', 'aaa', 'eosio')]
+
+    ordered = sorted(names)
+
+    assert [n.value() for n in ordered] == sorted(n.value() for n in names)
+    assert [str(n) for n in ordered] == ['aaa', 'eosio', 'zzz']
+
+    assert Name.from_str('1') < Name.from_str('a')
+    assert Name.from_str('a') <= Name.from_str('a')
+    assert Name.from_str('b') > Name.from_str('a.z')
+
+
+@pytest.mark.parametrize(
+    'name_str,match',
+    (
+        ('Alice', "'A' at index 0"),
+        ('bob-1', "'-' at index 3"),
+        ('eosio.token6', "'6' at index 11"),
+        ('zzzzzzzzzzzzz', "'z' at index 12"),
+        ('aaaaaaaaaaaaaa', '14 chars long'),
+    )
+)
+def test_validate_reports_offender(name_str: str, match: str):
+    with pytest.raises(ValueError, match=match):
+        Name.validate(name_str)
+
+
+def test_validate_accepts_valid():
+    for name_str in ('', 'eosio', 'eosio.token', 'zzzzzzzzzzzzj'):
+        assert Name.validate(name_str) is None
+
+
+def test_is_valid():
+    assert Name.is_valid('eosio.token')
+    assert not Name.is_valid('aaaaaaaaaaaaaa')
+    assert not Name.is_valid('Alice')
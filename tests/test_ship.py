@@ -0,0 +1,92 @@
+import struct
+
+from antelope_rs import Checksum256, ShipABI
+
+
+# minimal subset of the state history `result` definitions
+ship_abi = ShipABI.from_dict({
+    'version': 'eosio::abi/1.1',
+    'types': [],
+    'structs': [
+        {
+            'name': 'block_position',
+            'base': '',
+            'fields': [
+                {'name': 'block_num', 'type': 'uint32'},
+                {'name': 'block_id', 'type': 'checksum256'}
+            ]
+        },
+        {
+            'name': 'get_status_result_v0',
+            'base': '',
+            'fields': [
+                {'name': 'head', 'type': 'block_position'},
+                {'name': 'last_irreversible', 'type': 'block_position'}
+            ]
+        },
+        {
+            'name': 'get_blocks_result_v0',
+            'base': '',
+            'fields': [
+                {'name': 'head', 'type': 'block_position'},
+                {'name': 'last_irreversible', 'type': 'block_position'},
+                {'name': 'this_block', 'type': 'block_position?'},
+                {'name': 'prev_block', 'type': 'block_position?'},
+                {'name': 'block', 'type': 'bytes?'},
+                {'name': 'traces', 'type': 'bytes?'},
+                {'name': 'deltas', 'type': 'bytes?'}
+            ]
+        }
+    ],
+    'actions': [],
+    'tables': [],
+    'ricardian_clauses': [],
+    'error_messages': [],
+    'abi_extensions': [],
+    'variants': [
+        {'name': 'result', 'types': ['get_status_result_v0', 'get_blocks_result_v0']}
+    ]
+})
+
+
+def block_position(num: int) -> bytes:
+    return struct.pack('<I', num) + bytes([num & 0xff]) * 32
+
+
+def test_decode_get_blocks_result():
+    raw = (
+        b'\x01'                     # result variant index
+        + block_position(100)       # head
+        + block_position(90)        # last_irreversible
+        + b'\x01' + block_position(95)  # this_block
+        + b'\x00'                   # prev_block
+        + b'\x01\x03abc'            # block
+        + b'\x00'                   # traces
+        + b'\x01\x00'               # deltas, present but empty
+    )
+
+    result = ship_abi.unpack('result', raw)
+
+    assert result['type'] == 'get_blocks_result_v0'
+    assert result['head']['block_num'] == 100
+    assert result['head']['block_id'] == Checksum256.from_bytes(bytes([100]) * 32)
+    assert result['last_irreversible']['block_num'] == 90
+    assert result['this_block']['block_num'] == 95
+    assert result['prev_block'] is None
+    assert result['block'] == b'abc'
+    assert result['traces'] is None
+    assert result['deltas'] == b''
+
+    del result['type']
+    assert ship_abi.pack('get_blocks_result_v0', result) == raw[1:]
+
+
+def test_ship_abi_surface():
+    assert ship_abi.validate() is None
+    assert ship_abi.variant_names() == ['result']
+
+    tree = ship_abi.resolve_type_deep('result')
+    assert tree['kind'] == 'variant'
+    assert [t['resolved_name'] for t in tree['types']] == [
+        'get_status_result_v0', 'get_blocks_result_v0'
+    ]
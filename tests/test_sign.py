@@ -0,0 +1,357 @@
+import hashlib
+import json
+import zlib
+from types import SimpleNamespace
+
+import pytest
+
+from antelope_rs import (
+    ABI,
+    Name,
+    PrivateKey,
+    Signature,
+    build_transaction,
+    check_transaction_authorization,
+    recover_signers,
+    sign_tx,
+    verify_batch,
+)
+
+from conftest import make_abi
+
+
+chain_id = 'aca376f206b8fc25a6ed44dbdc66547c36c6c33e3a119ffbeaef943642f0e906'
+
+header = SimpleNamespace(
+    expiration=1_700_000_000,
+    ref_block_num=1,
+    ref_block_prefix=2,
+    delay_sec=0
+)
+
+
+token_abi = make_abi(
+    [
+        {
+            'name': 'transfer',
+            'base': '',
+            'fields': [
+                {'name': 'from', 'type': 'name'},
+                {'name': 'to', 'type': 'name'},
+                {'name': 'quantity', 'type': 'asset'},
+                {'name': 'memo', 'type': 'string'}
+            ]
+        }
+    ],
+    actions=[{'name': 'transfer', 'type': 'transfer', 'ricardian_contract': ''}]
+)
+
+
+def transfer_action(data) -> SimpleNamespace:
+    return SimpleNamespace(
+        account='eosio.token',
+        name='transfer',
+        authorization=[SimpleNamespace(actor='alice', permission='active')],
+        data=data
+    )
+
+
+def test_action_params_packed_with_abi():
+    key = PrivateKey.random(0)
+    params = {'from': 'alice', 'to': 'bob', 'quantity': '1.0000 EOS', 'memo': 'hi'}
+    abis = {'eosio.token': token_abi}
+
+    prepacked = build_transaction(
+        header, [transfer_action(token_abi.pack('transfer', params))], chain_id, [key]
+    )
+    packed = build_transaction(
+        header, [transfer_action(params)], chain_id, [key], abis=abis
+    )
+    signed = sign_tx(chain_id, header, [transfer_action(params)], key, abis=abis)
+
+    assert packed['packed_trx'] == prepacked['packed_trx']
+    assert signed['packed_trx'] == prepacked['packed_trx']
+
+    with pytest.raises(ValueError, match="No ABI for eosio.token provided"):
+        build_transaction(header, [transfer_action(params)], chain_id, [key])
+
+    with pytest.raises(KeyError, match='Action transfer not in ABI'):
+        build_transaction(
+            header, [transfer_action(params)], chain_id, [key],
+            abis={'eosio.token': ABI.from_dict({**json.loads(str(token_abi)), 'actions': []})}
+        )
+
+    # int sequences & bytearrays are taken as already packed
+    raw = build_transaction(header, [transfer_action(b'\x01\x02\x03')], chain_id, [key])
+    for data in ([1, 2, 3], bytearray(b'\x01\x02\x03')):
+        assert build_transaction(
+            header, [transfer_action(data)], chain_id, [key], abis=abis
+        )['packed_trx'] == raw['packed_trx']
+
+
+def test_header_usage_limits_default_to_zero():
+    action = transfer_action(b'')
+    tx = build_transaction(header, [action], chain_id, [PrivateKey.random(0)])
+
+    packed = bytes.fromhex(tx['packed_trx'])
+    assert packed[:13] == (
+        header.expiration.to_bytes(4, 'little')
+        + header.ref_block_num.to_bytes(2, 'little')
+        + header.ref_block_prefix.to_bytes(4, 'little')
+        + b'\x00'  # max_net_usage_words
+        + b'\x00'  # max_cpu_usage_ms
+        + b'\x00'  # delay_sec
+    )
+
+    explicit = SimpleNamespace(**vars(header), max_net_usage_words=0, max_cpu_usage_ms=0)
+    assert build_transaction(
+        explicit, [action], chain_id, [PrivateKey.random(0)]
+    )['packed_trx'] == tx['packed_trx']
+
+
+def test_verify_batch():
+    key = PrivateKey.random(0)
+    other = PrivateKey.random(0)
+    msg = b'hello world'
+
+    sig = Signature.from_bytes(key.sign_message(msg))
+
+    assert verify_batch([
+        (sig, msg, key.get_public()),
+        (sig, b'tampered', key.get_public()),
+        (sig, msg, other.get_public()),
+    ]) == [True, False, False]
+
+
+def test_recover_signers():
+    keys = [PrivateKey.random(0), PrivateKey.random(0)]
+    action = SimpleNamespace(
+        account='eosio.token',
+        name='transfer',
+        authorization=[
+            SimpleNamespace(actor='alice', permission='active'),
+            SimpleNamespace(actor='bob', permission='active')
+        ],
+        data=b''
+    )
+
+    tx = build_transaction(header, [action], chain_id, keys)
+
+    signers = recover_signers(chain_id, tx['packed_trx'], tx['signatures'])
+
+    assert signers == [key.get_public() for key in keys]
+
+    # key type byte followed by an out of range recovery id
+    corrupted = bytearray(Signature.from_str(tx['signatures'][0]).encode())
+    corrupted[1] = 0
+    with pytest.raises(ValueError, match='Malformed signature'):
+        recover_signers(chain_id, tx['packed_trx'], [bytes(corrupted)])
+
+
+def test_check_transaction_authorization():
+    alice, bob, carol = (PrivateKey.random(0) for _ in range(3))
+
+    def key_auth(key: PrivateKey) -> dict:
+        return {
+            'threshold': 1,
+            'keys': [{'key': str(key.get_public()), 'weight': 1}],
+            'accounts': []
+        }
+
+    authorities = {
+        'alice@active': key_auth(alice),
+        # needs both its own key & alice@active
+        'bob@active': {
+            'threshold': 2,
+            'keys': [{'key': str(bob.get_public()), 'weight': 1}],
+            'accounts': [{
+                'permission': {'actor': 'alice', 'permission': 'active'},
+                'weight': 1
+            }]
+        },
+        'carol@active': key_auth(carol)
+    }
+
+    action = SimpleNamespace(
+        account='eosio.token',
+        name='transfer',
+        authorization=[
+            SimpleNamespace(actor=actor, permission='active')
+            for actor in ('alice', 'bob', 'carol', 'dave')
+        ],
+        data=b''
+    )
+
+    tx = build_transaction(header, [action], chain_id, [alice, bob])
+
+    assert check_transaction_authorization(
+        tx['packed_trx'], chain_id, tx['signatures'], authorities
+    ) == {
+        'alice@active': True,
+        'bob@active': True,
+        'carol@active': False,
+        'dave@active': False
+    }
+
+    tx = build_transaction(header, [action], chain_id, [bob])
+
+    result = check_transaction_authorization(
+        tx['packed_trx'], chain_id, tx['signatures'], authorities
+    )
+    assert not result['alice@active']
+    assert not result['bob@active']
+
+
+def delegate(actor: str) -> dict:
+    return {
+        'threshold': 1,
+        'keys': [],
+        'accounts': [{
+            'permission': {'actor': actor, 'permission': 'active'},
+            'weight': 1
+        }]
+    }
+
+
+def test_authorization_depth_limit():
+    key = PrivateKey.random(0)
+    # a0 -> a1 -> ... -> aN, only the last one holds the key
+    def chain(n: int) -> dict:
+        authorities = {f'a{i}@active': delegate(f'a{i + 1}') for i in range(n)}
+        authorities[f'a{n}@active'] = {
+            'threshold': 1,
+            'keys': [{'key': str(key.get_public()), 'weight': 1}],
+            'accounts': []
+        }
+        return authorities
+
+    action = SimpleNamespace(
+        account='eosio.token',
+        name='transfer',
+        authorization=[SimpleNamespace(actor='a0', permission='active')],
+        data=b''
+    )
+    tx = build_transaction(header, [action], chain_id, [key])
+
+    # MAX_AUTH_DEPTH is 6
+    assert check_transaction_authorization(
+        tx['packed_trx'], chain_id, tx['signatures'], chain(6)
+    ) == {'a0@active': True}
+    assert check_transaction_authorization(
+        tx['packed_trx'], chain_id, tx['signatures'], chain(7)
+    ) == {'a0@active': False}
+
+
+def test_authorization_cycle():
+    key = PrivateKey.random(0)
+    authorities = {
+        'alice@active': delegate('bob'),
+        'bob@active': delegate('alice')
+    }
+    action = SimpleNamespace(
+        account='eosio.token',
+        name='transfer',
+        authorization=[SimpleNamespace(actor='alice', permission='active')],
+        data=b''
+    )
+    tx = build_transaction(header, [action], chain_id, [key])
+
+    # terminates & stays unsatisfied
+    assert check_transaction_authorization(
+        tx['packed_trx'], chain_id, tx['signatures'], authorities
+    ) == {'alice@active': False}
+
+
+def test_context_free_actions():
+    key = PrivateKey.random(0)
+    cfa = SimpleNamespace(
+        account='eosio.null',
+        name='nonce',
+        authorization=[],
+        data=b'\x01\x02'
+    )
+    action = SimpleNamespace(
+        account='eosio.token',
+        name='transfer',
+        authorization=[SimpleNamespace(actor='alice', permission='active')],
+        data=b''
+    )
+
+    tx = build_transaction(
+        header, [action], chain_id, [key],
+        context_free_actions=[cfa],
+        context_free_data=[b'cfd']
+    )
+    packed = bytes.fromhex(tx['packed_trx'])
+
+    # fixed header is 13 bytes, then the context free actions vector
+    cfa_raw = (
+        b'\x01'
+        + Name.from_str('eosio.null').encode()
+        + Name.from_str('nonce').encode()
+        + b'\x00'
+        + b'\x02\x01\x02'
+    )
+    assert packed[13:13 + len(cfa_raw)] == cfa_raw
+    assert tx['packed_context_free_data'] == (b'\x01\x03cfd').hex()
+
+    # signatures commit to the sha256 of the packed context free data
+    sign_data = (
+        bytes.fromhex(chain_id)
+        + packed
+        + hashlib.sha256(bytes.fromhex(tx['packed_context_free_data'])).digest()
+    )
+    assert Signature.from_str(tx['signatures'][0]).recover(sign_data) == key.get_public()
+
+    assert recover_signers(
+        chain_id, tx['packed_trx'], tx['signatures'],
+        packed_context_free_data=tx['packed_context_free_data']
+    ) == [key.get_public()]
+    # without the context free data the recovered key is wrong
+    assert recover_signers(
+        chain_id, tx['packed_trx'], tx['signatures']
+    ) != [key.get_public()]
+
+    plain = build_transaction(header, [action], chain_id, [key], context_free_actions=[cfa])
+    assert plain['packed_trx'] == tx['packed_trx']
+    assert plain['packed_context_free_data'] == ''
+
+
+def test_zlib_compression():
+    keys = [PrivateKey.random(0)]
+    action = SimpleNamespace(
+        account='eosio',
+        name='setcode',
+        authorization=[SimpleNamespace(actor='alice', permission='active')],
+        data=bytes(4096)
+    )
+
+    plain = build_transaction(header, [action], chain_id, keys)
+    tx = build_transaction(header, [action], chain_id, keys, compression='zlib')
+
+    assert plain['compression'] is False
+    assert tx['compression'] is True
+    assert len(tx['packed_trx']) < len(plain['packed_trx'])
+
+    packed = zlib.decompress(bytes.fromhex(tx['packed_trx']))
+    assert packed.hex() == plain['packed_trx']
+    assert recover_signers(chain_id, packed, tx['signatures']) == [keys[0].get_public()]
+    assert recover_signers(
+        chain_id, tx['packed_trx'], tx['signatures'], compression='zlib'
+    ) == [keys[0].get_public()]
+
+    cfd_tx = build_transaction(
+        header, [action], chain_id, keys,
+        context_free_data=[b'cfd'], compression='zlib'
+    )
+    assert recover_signers(
+        chain_id, cfd_tx['packed_trx'], cfd_tx['signatures'],
+        packed_context_free_data=cfd_tx['packed_context_free_data'],
+        compression='zlib'
+    ) == [keys[0].get_public()]
+
+    with pytest.raises(ValueError, match='Error decompressing trx'):
+        recover_signers(chain_id, plain['packed_trx'], plain['signatures'], compression='zlib')
+
+    with pytest.raises(ValueError, match='expected none or zlib'):
+        build_transaction(header, [action], chain_id, keys, compression='gzip')
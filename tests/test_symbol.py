@@ -0,0 +1,65 @@
+import pytest
+
+from antelope_rs import Symbol, SymbolCode
+
+
+def test_from_code():
+    code = SymbolCode.from_str('EOS')
+
+    assert Symbol.from_code(code, 4) == Symbol.from_str('4,EOS')
+    assert Symbol.from_code('TLOS', 0) == Symbol.from_str('0,TLOS')
+
+    with pytest.raises(ValueError):
+        Symbol.from_code(code, 19)
+
+
+def test_symbol_code_int():
+    # chars packed little endian, one byte each
+    assert int(SymbolCode.from_str('EOS')) == 0x534f45
+
+
+def test_symbol_code_ordering_and_hash():
+    codes = [SymbolCode.from_str(c) for c in ('TLOS', 'EOS', 'A', 'WAX')]
+
+    assert sorted(codes) == sorted(codes, key=int)
+    assert SymbolCode.from_str('A') < SymbolCode.from_str('B')
+    assert SymbolCode.from_str('B') >= SymbolCode.from_str('B')
+
+    assert hash(SymbolCode.from_str('EOS')) == hash(SymbolCode.from_str('EOS'))
+    assert len(set(codes + codes)) == len(codes)
+
+
+def test_from_parts():
+    assert Symbol.from_parts('EOS', 4) == Symbol.from_str('4,EOS')
+    assert Symbol.try_from(('EOS', 4)) == Symbol.from_str('4,EOS')
+    assert Symbol.from_parts('A', 18).precision == 18
+
+    with pytest.raises(ValueError):
+        Symbol.from_parts('EOS', 19)
+
+    with pytest.raises(ValueError):
+        Symbol.from_parts('eos', 4)
+
+    with pytest.raises(ValueError):
+        Symbol.try_from(('TOOLONGCODE', 4))
+
+
+def test_name():
+    assert Symbol.from_str('4,EOS').name == '4,EOS'
+
+
+def test_from_bytes_length():
+    sym = Symbol.from_str('4,EOS')
+
+    assert Symbol.from_bytes(sym.encode()) == sym
+
+    with pytest.raises(ValueError, match='at least 8 bytes'):
+        Symbol.from_bytes(sym.encode()[:3])
+
+
+@pytest.mark.parametrize(
+    'precision,divisor',
+    ((0, 1), (4, 10_000), (8, 100_000_000))
+)
+def test_precision_divisor(precision: int, divisor: int):
+    assert Symbol.from_parts('EOS', precision).precision_divisor == divisor
@@ -0,0 +1,143 @@
+from datetime import datetime, timezone
+
+import pytest
+
+from antelope_rs import BlockTimestamp, TimePoint, TimePointSec
+
+from conftest import make_abi
+
+
+def test_time_point_shift():
+    tp = TimePoint.from_int(1_000_000)
+
+    assert tp + 500 == TimePoint.from_int(1_000_500)
+    assert tp - 500 == TimePoint.from_int(999_500)
+    assert tp + -1_000_000 == TimePoint.from_int(0)
+
+    with pytest.raises(OverflowError):
+        tp - 1_000_001
+
+    with pytest.raises(OverflowError):
+        TimePoint.from_int(2 ** 64 - 1) + 1
+
+
+def test_time_point_diff():
+    a = TimePoint.from_int(1_000_000)
+    b = TimePoint.from_int(250)
+
+    assert a - b == 999_750
+    assert b - a == -999_750
+    assert a - a == 0
+
+    with pytest.raises(OverflowError):
+        TimePoint.from_int(2 ** 64 - 1) - TimePoint.from_int(0)
+
+
+def test_time_point_sec_roundtrip():
+    tps = TimePointSec.from_str('2023-11-14T22:13:20')
+
+    assert int(tps) == 1_700_000_000
+    assert TimePointSec.from_int(1_700_000_000) == tps
+    assert TimePointSec.from_bytes(tps.encode()) == tps
+    assert tps.encode() == (1_700_000_000).to_bytes(4, 'little')
+    assert TimePointSec.from_str(str(tps)) == tps
+
+    assert TimePointSec.try_from('2023-11-14T22:13:20') == tps
+    assert TimePointSec.try_from(1_700_000_000) == tps
+
+
+def test_time_point_sec_ordering():
+    early = TimePointSec.from_int(0)
+    late = TimePointSec.from_str('2023-11-14T22:13:20')
+
+    assert early < late and late >= early
+    assert sorted([late, early]) == [early, late]
+    assert len({late, TimePointSec.from_int(int(late))}) == 1
+
+
+def test_block_timestamp_slot():
+    ts = BlockTimestamp.from_str('2018-06-01T12:00:00.000')
+
+    assert ts.slot == 1_162_339_200
+    assert BlockTimestamp.from_slot(ts.slot) == ts
+    assert BlockTimestamp.from_bytes(ts.encode()) == ts
+    assert ts.encode() == ts.slot.to_bytes(4, 'little')
+
+    half = BlockTimestamp.from_str('2018-06-01T12:00:00.500')
+    assert half.slot == ts.slot + 1
+    assert ts < half and half >= ts
+
+    assert ts.to_time_point_sec() == TimePointSec.from_str('2018-06-01T12:00:00')
+    assert half.to_time_point_sec() == ts.to_time_point_sec()
+
+
+@pytest.mark.parametrize(
+    'dt,elapsed',
+    (
+        (datetime(1970, 1, 1, tzinfo=timezone.utc), 0),
+        (
+            datetime(2023, 11, 14, 22, 13, 20, 123456, tzinfo=timezone.utc),
+            1_700_000_000_123_456
+        ),
+    ),
+    ids=('epoch', 'micros')
+)
+def test_time_point_datetime(dt: datetime, elapsed: int):
+    tp = TimePoint.from_datetime(dt)
+
+    assert tp == TimePoint.from_int(elapsed)
+    assert tp.to_datetime() == dt
+    assert tp.to_datetime().tzinfo is timezone.utc
+
+    # naive datetimes are taken as UTC
+    assert TimePoint.from_datetime(dt.replace(tzinfo=None)) == tp
+
+
+def test_time_point_datetime_before_epoch():
+    with pytest.raises(ValueError):
+        TimePoint.from_datetime(datetime(1969, 12, 31, tzinfo=timezone.utc))
+
+
+def test_pack_malformed_time_point_sec():
+    abi = make_abi([{
+        'name': 'schedule',
+        'base': '',
+        'fields': [{'name': 'at', 'type': 'time_point_sec'}]
+    }])
+
+    raw = (1_700_000_000).to_bytes(4, 'little')
+    assert abi.pack('schedule', {'at': 1_700_000_000}) == raw
+
+    with pytest.raises(ValueError, match='not-a-date'):
+        abi.pack('schedule', {'at': 'not-a-date'})
+
+
+@pytest.mark.parametrize(
+    'time_str,micros',
+    (
+        ('2023-01-01T00:00:00', 0),
+        ('2023-01-01T00:00:00.5', 500_000),
+        ('2023-01-01T00:00:00.500', 500_000),
+        ('2023-01-01T00:00:00.123456', 123_456),
+    ),
+    ids=('no_fraction', 'half', 'millis', 'micros')
+)
+def test_time_point_fractional_seconds(time_str: str, micros: int):
+    base = 1_672_531_200_000_000
+
+    assert TimePoint.from_str(time_str) == TimePoint.from_int(base + micros)
+
+
+def test_unpack_huge_time_point_sec():
+    abi = make_abi([{
+        'name': 'stamps',
+        'base': '',
+        'fields': [
+            {'name': 'sec', 'type': 'time_point_sec'},
+            {'name': 'block', 'type': 'block_timestamp_type'}
+        ]
+    }])
+
+    raw = b'\xff' * 8
+
+    assert abi.unpack('stamps', raw) == {'sec': 2 ** 32 - 1, 'block': 2 ** 32 - 1}